@@ -0,0 +1,239 @@
+use std::{env, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    file::STDIN_NAME,
+    report::PartResult,
+    solver::{Answer, Part},
+};
+
+/// A known-good answer for one part of a day, for a specific input file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedAnswer {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: Answer,
+}
+
+/// The contents of an `answers.toml` file:
+///
+/// ```toml
+/// [[answers]]
+/// day = 5
+/// part = 1
+/// input = "day5/source_data/input.txt"
+/// answer = "143"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    #[serde(default)]
+    pub answers: Vec<RecordedAnswer>,
+}
+
+/// How a computed answer compares with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Match,
+    Mismatch {
+        expected: Answer,
+        actual: Option<Answer>,
+    },
+    Unrecorded,
+}
+
+impl AnswerStore {
+    pub fn from_toml(data: &str) -> Result<AnswerStore, toml::de::Error> {
+        return toml::from_str(data);
+    }
+
+    pub fn to_toml(&self) -> String {
+        return toml::to_string(self).expect("answer store is always serializable");
+    }
+
+    /// Reads the store at `path`, which must exist.
+    pub fn load(path: &str) -> io::Result<AnswerStore> {
+        let data = fs::read_to_string(path)?;
+        return AnswerStore::from_toml(&data)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+    }
+
+    /// Like [`AnswerStore::load`], but a missing file is an empty store, for recording into.
+    pub fn load_or_default(path: &str) -> io::Result<AnswerStore> {
+        if !Path::new(path).exists() {
+            return Ok(AnswerStore::default());
+        }
+        return AnswerStore::load(path);
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        return fs::write(path, self.to_toml());
+    }
+
+    pub fn get(&self, day: u32, part: Part, input: &str) -> Option<&Answer> {
+        return self
+            .answers
+            .iter()
+            .find(|recorded| {
+                recorded.day == day && recorded.part == part.number() && recorded.input == input
+            })
+            .map(|recorded| &recorded.answer);
+    }
+
+    /// Records a solved part's answer, replacing any earlier answer for the same day, part and input.
+    pub fn record(&mut self, input: &str, result: &PartResult) {
+        let Some(answer) = &result.answer else {
+            return;
+        };
+        let recorded = RecordedAnswer {
+            day: result.day,
+            part: result.part.number(),
+            input: input.to_string(),
+            answer: answer.clone(),
+        };
+        match self.answers.iter_mut().find(|existing| {
+            existing.day == recorded.day
+                && existing.part == recorded.part
+                && existing.input == recorded.input
+        }) {
+            Some(existing) => *existing = recorded,
+            None => self.answers.push(recorded),
+        }
+    }
+
+    pub fn check(&self, input: &str, result: &PartResult) -> CheckOutcome {
+        return match self.get(result.day, result.part, input) {
+            None => CheckOutcome::Unrecorded,
+            Some(expected) if result.answer.as_ref() == Some(expected) => CheckOutcome::Match,
+            Some(expected) => CheckOutcome::Mismatch {
+                expected: expected.clone(),
+                actual: result.answer.clone(),
+            },
+        };
+    }
+}
+
+/// The name answers for an input are stored under: its path relative to the current directory
+/// when it's inside it, or its absolute path otherwise, so `./day5.txt` and `day5.txt` share
+/// answers.
+///
+/// Input from stdin has no name to store answers under, so it's an error.
+pub fn get_answer_key(input_name: &str) -> Result<String, String> {
+    if input_name == STDIN_NAME {
+        return Err("answers can't be kept for input from stdin; read it from a file".to_string());
+    }
+    let path = fs::canonicalize(input_name).map_err(|err| err.to_string())?;
+    let relative = env::current_dir()
+        .and_then(fs::canonicalize)
+        .ok()
+        .and_then(|current_dir| path.strip_prefix(current_dir).ok().map(Path::to_path_buf));
+    return Ok(relative.unwrap_or(path).to_string_lossy().to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[[answers]]
+day = 5
+part = 1
+input = "day5.txt"
+answer = "143"
+"#;
+
+    fn get_result(part: Part, answer: Option<&str>) -> PartResult {
+        return PartResult {
+            input: "day5.txt".to_string(),
+            day: 5,
+            part,
+            answer: answer.map(|answer| answer.to_string()),
+            elapsed: Duration::ZERO,
+        };
+    }
+
+    #[test]
+    fn looks_up_by_day_part_and_input() {
+        let store = AnswerStore::from_toml(ANSWERS).unwrap();
+        assert_eq!(
+            store.get(5, Part::One, "day5.txt"),
+            Some(&"143".to_string())
+        );
+        assert_eq!(store.get(5, Part::Two, "day5.txt"), None);
+        assert_eq!(store.get(5, Part::One, "other.txt"), None);
+        assert_eq!(store.get(4, Part::One, "day5.txt"), None);
+    }
+
+    #[test]
+    fn checks_results_against_recorded_answers() {
+        let store = AnswerStore::from_toml(ANSWERS).unwrap();
+        assert_eq!(
+            store.check("day5.txt", &get_result(Part::One, Some("143"))),
+            CheckOutcome::Match
+        );
+        assert_eq!(
+            store.check("day5.txt", &get_result(Part::One, Some("142"))),
+            CheckOutcome::Mismatch {
+                expected: "143".to_string(),
+                actual: Some("142".to_string())
+            }
+        );
+        assert_eq!(
+            store.check("day5.txt", &get_result(Part::One, None)),
+            CheckOutcome::Mismatch {
+                expected: "143".to_string(),
+                actual: None
+            }
+        );
+        assert_eq!(
+            store.check("day5.txt", &get_result(Part::Two, Some("123"))),
+            CheckOutcome::Unrecorded
+        );
+    }
+
+    #[test]
+    fn record_replaces_existing_answers() {
+        let mut store = AnswerStore::from_toml(ANSWERS).unwrap();
+        store.record("day5.txt", &get_result(Part::One, Some("144")));
+        store.record("day5.txt", &get_result(Part::Two, Some("123")));
+        store.record("day5.txt", &get_result(Part::Two, None));
+        assert_eq!(store.answers.len(), 2);
+        assert_eq!(
+            store.get(5, Part::One, "day5.txt"),
+            Some(&"144".to_string())
+        );
+
+        let reloaded = AnswerStore::from_toml(&store.to_toml()).unwrap();
+        assert_eq!(reloaded, store);
+    }
+
+    #[test]
+    fn keys_are_normalised_paths() {
+        assert_eq!(get_answer_key("./Cargo.toml"), Ok("Cargo.toml".to_string()));
+        assert_eq!(get_answer_key("Cargo.toml"), Ok("Cargo.toml".to_string()));
+        assert_eq!(
+            get_answer_key("src/../Cargo.toml"),
+            Ok("Cargo.toml".to_string())
+        );
+        assert!(get_answer_key(STDIN_NAME).is_err());
+        assert!(get_answer_key("./this/file/does/not/exist.txt").is_err());
+    }
+
+    #[test]
+    fn only_recording_accepts_a_missing_store() {
+        let path = "./this/file/does/not/exist.toml";
+        assert!(AnswerStore::load(path).is_err());
+        assert_eq!(
+            AnswerStore::load_or_default(path).unwrap(),
+            AnswerStore::default()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_store() {
+        assert!(AnswerStore::from_toml("[[answers]]\nday = \"five\"").is_err());
+    }
+}
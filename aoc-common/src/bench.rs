@@ -0,0 +1,136 @@
+use std::{fmt, time::Duration};
+
+use serde_json::json;
+
+use crate::{report::OutputFormat, solver::Part};
+
+/// A timed step of running a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Min, median and max timings of a phase across every benchmark run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchSummary {
+    pub input: String,
+    pub day: u32,
+    pub phase: Phase,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl BenchSummary {
+    /// Summarises the samples; there must be at least one.
+    pub fn from_samples(
+        input: &str,
+        day: u32,
+        phase: Phase,
+        samples: &Vec<Duration>,
+    ) -> BenchSummary {
+        let mut sorted = samples.clone();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        return BenchSummary {
+            input: input.to_string(),
+            day,
+            phase,
+            runs: sorted.len(),
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        };
+    }
+
+    pub fn to_text(&self) -> String {
+        return format!(
+            "Day {} {} over {} runs: min {:.3?}, median {:.3?}, max {:.3?}",
+            self.day, self.phase, self.runs, self.min, self.median, self.max
+        );
+    }
+
+    pub fn to_json(&self) -> String {
+        return json!({
+            "input": self.input,
+            "day": self.day,
+            "phase": self.phase.to_string(),
+            "runs": self.runs,
+            "min_ms": self.min.as_secs_f64() * 1000.0,
+            "median_ms": self.median.as_secs_f64() * 1000.0,
+            "max_ms": self.max.as_secs_f64() * 1000.0,
+        })
+        .to_string();
+    }
+
+    pub fn format(&self, format: OutputFormat) -> String {
+        return match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Json => self.to_json(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_samples(micros: &[u64]) -> Vec<Duration> {
+        return micros.iter().map(|m| Duration::from_micros(*m)).collect();
+    }
+
+    #[test]
+    fn summarises_odd_number_of_samples() {
+        let summary =
+            BenchSummary::from_samples("input.txt", 1, Phase::Parse, &get_samples(&[30, 10, 20]));
+        assert_eq!(summary.runs, 3);
+        assert_eq!(summary.min, Duration::from_micros(10));
+        assert_eq!(summary.median, Duration::from_micros(20));
+        assert_eq!(summary.max, Duration::from_micros(30));
+    }
+
+    #[test]
+    fn median_of_even_samples_is_the_mean_of_the_middle_two() {
+        let summary = BenchSummary::from_samples(
+            "input.txt",
+            1,
+            Phase::Solve(Part::Two),
+            &get_samples(&[40, 10, 20, 30]),
+        );
+        assert_eq!(summary.median, Duration::from_micros(25));
+    }
+
+    #[test]
+    fn formats_summary() {
+        let summary = BenchSummary::from_samples(
+            "input.txt",
+            6,
+            Phase::Solve(Part::One),
+            &get_samples(&[1000, 2000, 3000]),
+        );
+        assert_eq!(
+            summary.format(OutputFormat::Text),
+            "Day 6 part 1 over 3 runs: min 1.000ms, median 2.000ms, max 3.000ms"
+        );
+        assert_eq!(
+            summary.format(OutputFormat::Json),
+            r#"{"day":6,"input":"input.txt","max_ms":3.0,"median_ms":2.0,"min_ms":1.0,"phase":"part 1","runs":3}"#
+        );
+    }
+}
@@ -0,0 +1,40 @@
+/// Splits raw puzzle input into blocks of lines separated by blank lines.
+pub fn get_blocks_from_raw_data(data: &str) -> Vec<Vec<&str>> {
+    let mut lines = data.lines().peekable();
+    let mut blocks = Vec::new();
+    while lines.peek().is_some() {
+        let block: Vec<_> = lines
+            .by_ref()
+            .take_while(|l| !l.trim().is_empty())
+            .collect();
+        blocks.push(block);
+    }
+    return blocks;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let data = "47|53\n97|13\n\n75,47,61\n97,61,53\n";
+        let blocks = get_blocks_from_raw_data(data);
+        assert_eq!(
+            blocks,
+            vec![vec!["47|53", "97|13"], vec!["75,47,61", "97,61,53"]]
+        );
+    }
+
+    #[test]
+    fn whitespace_only_lines_separate_blocks() {
+        let data = "a\n   \nb\nc";
+        let blocks = get_blocks_from_raw_data(data);
+        assert_eq!(blocks, vec![vec!["a"], vec!["b", "c"]]);
+    }
+
+    #[test]
+    fn empty_input_has_no_blocks() {
+        assert!(get_blocks_from_raw_data("").is_empty());
+    }
+}
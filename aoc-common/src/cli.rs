@@ -0,0 +1,154 @@
+use clap::{ArgAction, Args, Parser};
+
+use crate::{
+    file::{self, PuzzleInputs, UnreadableInput},
+    report::OutputFormat,
+    solver::Part,
+};
+
+/// Input options shared by every day's binary.
+#[derive(Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Puzzle input files, solved in turn; `-` reads from stdin.
+    #[arg(short, long = "file", value_name = "FILE", required = true, num_args = 1..)]
+    pub files: Vec<String>,
+}
+
+impl InputArgs {
+    /// Reads every puzzle input, keeping the ones that can't be read for the runner to report
+    /// and skip.
+    pub fn load(&self) -> PuzzleInputs {
+        let mut puzzle_inputs = Vec::new();
+        for file_path in &self.files {
+            let puzzle_input = file::load_puzzle_input(file_path).map_err(|err| UnreadableInput {
+                file_path: file_path.clone(),
+                err,
+            });
+            puzzle_inputs.push(puzzle_input);
+        }
+        return puzzle_inputs;
+    }
+}
+
+/// Options controlling how a day's solver is run, shared by every day's binary.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RunArgs {
+    /// Solve only this part; both parts are solved when omitted.
+    #[arg(short, long, value_name = "PART")]
+    pub part: Option<Part>,
+    /// Log what the solver is doing; repeat for more detail (-v, -vv, -vvv).
+    #[arg(short, long, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+    /// Only print answers and errors.
+    #[arg(short, long)]
+    pub quiet: bool,
+    /// How answers are written to stdout.
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t)]
+    pub format: OutputFormat,
+    /// Time parsing and each part over this many runs and report min/median/max.
+    #[arg(long, value_name = "RUNS", value_parser = clap::value_parser!(u32).range(1..))]
+    pub bench: Option<u32>,
+    /// Compare answers with those recorded in the answers file, exiting non-zero if the file or an
+    /// answer is missing, or an answer doesn't match.
+    #[arg(long, conflicts_with = "record")]
+    pub check: bool,
+    /// Save answers to the answers file, replacing any recorded for the same input; input from
+    /// stdin can't be recorded.
+    #[arg(long)]
+    pub record: bool,
+    /// The answers file used by --check and --record.
+    #[arg(long, value_name = "FILE", default_value = "answers.toml")]
+    pub answers: String,
+}
+
+/// The command line for days that only need an input file.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_file_flag() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt"]).unwrap();
+        assert_eq!(cli.input.files, vec!["input.txt"]);
+        assert_eq!(cli.run.part, None);
+    }
+
+    #[test]
+    fn parses_several_files() {
+        let cli = Cli::try_parse_from(["day", "-f", "a.txt", "b.txt", "--file", "-"]).unwrap();
+        assert_eq!(cli.input.files, vec!["a.txt", "b.txt", "-"]);
+    }
+
+    #[test]
+    fn load_keeps_going_past_unreadable_files() {
+        let cli = Cli::try_parse_from(["day", "-f", "missing.txt", "Cargo.toml"]).unwrap();
+        let puzzle_inputs = cli.input.load();
+        assert_eq!(puzzle_inputs.len(), 2);
+        let err = puzzle_inputs[0].as_ref().unwrap_err();
+        assert_eq!(err.file_path, "missing.txt");
+        assert_eq!(puzzle_inputs[1].as_ref().unwrap().name, "Cargo.toml");
+    }
+
+    #[test]
+    fn parses_part_flag() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "--part", "2"]).unwrap();
+        assert_eq!(cli.run.part, Some(Part::Two));
+        assert!(Cli::try_parse_from(["day", "-f", "input.txt", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn counts_verbose_flags() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "-vv"]).unwrap();
+        assert_eq!(cli.run.verbose, 2);
+        assert!(!cli.run.quiet);
+    }
+
+    #[test]
+    fn quiet_conflicts_with_verbose() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "--quiet"]).unwrap();
+        assert!(cli.run.quiet);
+        assert!(Cli::try_parse_from(["day", "-f", "input.txt", "-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn parses_format_flag() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt"]).unwrap();
+        assert_eq!(cli.run.format, OutputFormat::Text);
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "--format", "json"]).unwrap();
+        assert_eq!(cli.run.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn parses_bench_flag() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "--bench", "10"]).unwrap();
+        assert_eq!(cli.run.bench, Some(10));
+        assert!(Cli::try_parse_from(["day", "-f", "input.txt", "--bench", "0"]).is_err());
+    }
+
+    #[test]
+    fn parses_answer_flags() {
+        let cli = Cli::try_parse_from(["day", "-f", "input.txt", "--check"]).unwrap();
+        assert!(cli.run.check);
+        assert_eq!(cli.run.answers, "answers.toml");
+        let cli =
+            Cli::try_parse_from(["day", "-f", "input.txt", "--record", "--answers", "a.toml"])
+                .unwrap();
+        assert!(cli.run.record);
+        assert_eq!(cli.run.answers, "a.toml");
+        assert!(Cli::try_parse_from(["day", "-f", "input.txt", "--check", "--record"]).is_err());
+    }
+
+    #[test]
+    fn file_flag_is_required() {
+        assert!(Cli::try_parse_from(["day"]).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A problem found while parsing puzzle input, pointing at the offending text.
+///
+/// Lines and columns are 1-based; columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which must be a slice of `raw_data` so its position can be found.
+    pub fn at(raw_data: &str, token: &str, message: impl Into<String>) -> ParseError {
+        let start = raw_data.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(start)
+            .min(raw_data.len());
+        let (line, column) = get_line_and_column(raw_data, offset);
+        return ParseError {
+            line,
+            column,
+            text: token.to_string(),
+            message: message.into(),
+        };
+    }
+
+    /// Builds an error for input that ended before something required was found.
+    pub fn at_end(raw_data: &str, message: impl Into<String>) -> ParseError {
+        return ParseError::at(raw_data, &raw_data[raw_data.len()..], message);
+    }
+
+    /// Renders the error with the offending line underlined, in the style of a compiler diagnostic.
+    pub fn render(&self, source_name: &str, raw_data: &str) -> String {
+        let mut rendered = format!(
+            "error: {}\n --> {}:{}:{}\n",
+            self.message, source_name, self.line, self.column
+        );
+        if let Some(source_line) = raw_data.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            rendered.push_str(&format!("{} |\n", gutter));
+            rendered.push_str(&format!("{} | {}\n", self.line, source_line));
+            rendered.push_str(&format!(
+                "{} | {}{}\n",
+                gutter,
+                " ".repeat(self.column - 1),
+                underline
+            ));
+        }
+        return rendered;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: '{}'",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why a part couldn't be answered even though its input parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        return SolveError {
+            message: message.into(),
+        };
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Parses `token`, a slice of `raw_data`, reporting where it was if it isn't a valid `T`.
+pub fn parse_token<T>(raw_data: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    return token
+        .parse::<T>()
+        .map_err(|err| ParseError::at(raw_data, token, format!("invalid number ({})", err)));
+}
+
+fn get_line_and_column(raw_data: &str, offset: usize) -> (usize, usize) {
+    let before = &raw_data[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    return (line, column);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_token_line_and_column() {
+        let raw_data = "190: 10 19\n3267: 81 4x 27\n";
+        let token = &raw_data[20..22];
+        assert_eq!(token, "4x");
+        let err = ParseError::at(raw_data, token, "invalid number");
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(err.text, "4x");
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let raw_data = "é x";
+        let err = ParseError::at(raw_data, &raw_data[3..], "bad");
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn at_end_points_past_the_last_line() {
+        let err = ParseError::at_end("a\nb", "missing block");
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "");
+    }
+
+    #[test]
+    fn parse_token_reports_invalid_numbers() {
+        let raw_data = "1 2 x";
+        assert_eq!(parse_token::<i32>(raw_data, &raw_data[2..3]), Ok(2));
+        let err = parse_token::<i32>(raw_data, &raw_data[4..5]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert!(err.message.starts_with("invalid number"));
+    }
+
+    #[test]
+    fn render_underlines_the_offending_text() {
+        let raw_data = "47|53\n97|x3\n";
+        let err = ParseError::at(raw_data, &raw_data[9..11], "invalid number");
+        let rendered = err.render("input.txt", raw_data);
+        assert_eq!(
+            rendered,
+            "error: invalid number\n --> input.txt:2:4\n  |\n2 | 97|x3\n  |    ^^\n"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+};
+
+/// Raw puzzle input along with where it came from, for labelling answers and diagnostics.
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    pub name: String,
+    pub data: String,
+}
+
+/// A puzzle input file that couldn't be read, named as it was given.
+#[derive(Debug)]
+pub struct UnreadableInput {
+    pub file_path: String,
+    pub err: io::Error,
+}
+
+impl fmt::Display for UnreadableInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unable to read file '{}': {}", self.file_path, self.err)
+    }
+}
+
+impl Error for UnreadableInput {}
+
+/// Puzzle inputs in the order they were given, each read or saying why it couldn't be.
+pub type PuzzleInputs = Vec<Result<PuzzleInput, UnreadableInput>>;
+
+/// Puzzle input still to be read, for days that can work through it a buffer at a time.
+pub struct PuzzleReader {
+    pub name: String,
+    pub reader: Box<dyn BufRead>,
+}
+
+/// Why puzzle input read a buffer at a time couldn't be used: reading it failed, or what was read
+/// was wrong in the way `E` describes.
+#[derive(Debug)]
+pub enum ReadError<E> {
+    Io(io::Error),
+    Input(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Input(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for ReadError<E> {}
+
+impl<E> From<io::Error> for ReadError<E> {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// The file path that reads the puzzle input from stdin instead.
+pub const STDIN_PATH: &str = "-";
+/// The name puzzle input read from stdin is labelled with.
+pub const STDIN_NAME: &str = "<stdin>";
+
+pub fn load_file(file_path: &str) -> io::Result<String> {
+    return fs::read_to_string(file_path);
+}
+
+pub fn load_reader<R: Read>(mut reader: R) -> io::Result<String> {
+    let mut data = String::new();
+    reader.read_to_string(&mut data)?;
+    return Ok(data);
+}
+
+/// Loads the puzzle input from a file, or from stdin when the path is `-`.
+pub fn load_puzzle_input(file_path: &str) -> io::Result<PuzzleInput> {
+    if file_path == STDIN_PATH {
+        return Ok(PuzzleInput {
+            name: STDIN_NAME.to_string(),
+            data: load_reader(io::stdin().lock())?,
+        });
+    }
+    return Ok(PuzzleInput {
+        name: file_path.to_string(),
+        data: load_file(file_path)?,
+    });
+}
+
+/// Opens the puzzle input in a file, or stdin when the path is `-`, without reading it yet.
+pub fn open_puzzle_reader(file_path: &str) -> io::Result<PuzzleReader> {
+    if file_path == STDIN_PATH {
+        return Ok(PuzzleReader {
+            name: STDIN_NAME.to_string(),
+            reader: Box::new(io::stdin().lock()),
+        });
+    }
+    return Ok(PuzzleReader {
+        name: file_path.to_string(),
+        reader: Box::new(BufReader::new(File::open(file_path)?)),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let result = load_file("./this/file/does/not/exist.txt");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn loads_file_contents() {
+        let data = load_file("./Cargo.toml").unwrap();
+        assert!(data.contains("aoc-common"));
+    }
+
+    #[test]
+    fn loads_reader_contents() {
+        let data = load_reader("3   4\n4   3\n".as_bytes()).unwrap();
+        assert_eq!(data, "3   4\n4   3\n");
+    }
+
+    #[test]
+    fn puzzle_input_is_named_after_its_file() {
+        let puzzle_input = load_puzzle_input("./Cargo.toml").unwrap();
+        assert_eq!(puzzle_input.name, "./Cargo.toml");
+    }
+
+    #[test]
+    fn opens_readers_without_reading() {
+        let mut puzzle_reader = open_puzzle_reader("./Cargo.toml").unwrap();
+        assert_eq!(puzzle_reader.name, "./Cargo.toml");
+        let mut first_line = String::new();
+        puzzle_reader.reader.read_line(&mut first_line).unwrap();
+        assert_eq!(first_line, "[package]\n");
+        assert!(open_puzzle_reader("./this/file/does/not/exist.txt").is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::ParseError;
+
+pub type Grid = Vec<Vec<char>>;
+
+/// Parses raw puzzle input into a row-major grid of characters, one row per line.
+pub fn get_grid_from_raw_data(data: &str) -> Grid {
+    let mut grid = Vec::new();
+    for line in data.lines() {
+        let row: Vec<char> = line.chars().collect();
+        grid.push(row);
+    }
+    return grid;
+}
+
+/// Checks that every line of raw puzzle input is as long as the first, pointing at the first one
+/// that isn't.
+pub fn check_rectangular(data: &str) -> Result<(), ParseError> {
+    let mut lines = data.lines();
+    let Some(first) = lines.next() else {
+        return Ok(());
+    };
+    let width = first.chars().count();
+    for line in lines {
+        let length = line.chars().count();
+        if length != width {
+            return Err(ParseError::at(
+                data,
+                line,
+                format!(
+                    "expected a row of {} cells like the first, found {}",
+                    width, length
+                ),
+            ));
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = get_grid_from_raw_data("..#\n#..\n");
+        assert_eq!(grid, vec![vec!['.', '.', '#'], vec!['#', '.', '.']]);
+        assert_eq!(grid[1][0], '#');
+    }
+
+    #[test]
+    fn rectangular_grids_have_equal_rows() {
+        assert_eq!(check_rectangular("..#\n#..\n"), Ok(()));
+        assert_eq!(check_rectangular(""), Ok(()));
+        let err = check_rectangular("..#\n#.\n...").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "#."));
+    }
+
+    #[test]
+    fn empty_input_is_an_empty_grid() {
+        assert!(get_grid_from_raw_data("").is_empty());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod answers;
+pub mod bench;
+pub mod blocks;
+pub mod cli;
+pub mod error;
+pub mod file;
+pub mod grid;
+pub mod logging;
+pub mod report;
+pub mod solver;
@@ -0,0 +1,62 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes log records to stderr so stdout only ever carries answers.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        return metadata.level() <= log::max_level();
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Maps `-q` and the number of `-v` flags to a log level; warnings are shown by default.
+pub fn get_level_filter(verbose: u8, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+    return match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+}
+
+/// Installs the stderr logger, if it isn't already, and sets the level to log at.
+pub fn init(verbose: u8, quiet: bool) {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(get_level_filter(verbose, quiet));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_level_shows_warnings() {
+        assert_eq!(get_level_filter(0, false), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn each_verbose_flag_adds_detail() {
+        assert_eq!(get_level_filter(1, false), LevelFilter::Info);
+        assert_eq!(get_level_filter(2, false), LevelFilter::Debug);
+        assert_eq!(get_level_filter(3, false), LevelFilter::Trace);
+        assert_eq!(get_level_filter(9, false), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn quiet_only_shows_errors() {
+        assert_eq!(get_level_filter(0, true), LevelFilter::Error);
+    }
+}
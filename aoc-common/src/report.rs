@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde_json::json;
+
+use crate::solver::{Answer, Part};
+
+/// How the answers for each part are written to stdout.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// One human readable line per part.
+    #[default]
+    Text,
+    /// One JSON record per line: `{"input", "day", "part", "answer", "elapsed_ms"}`.
+    Json,
+}
+
+/// The outcome of solving a single part of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    /// The name of the puzzle input the part was solved for.
+    pub input: String,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn to_text(&self) -> String {
+        return match &self.answer {
+            Some(answer) => format!(
+                "Day {} part {}: {} ({:.3?})",
+                self.day, self.part, answer, self.elapsed
+            ),
+            None => format!("Day {} part {}: not solved yet", self.day, self.part),
+        };
+    }
+
+    /// Unsolved parts have a `null` answer.
+    pub fn to_json(&self) -> String {
+        return json!({
+            "input": self.input,
+            "day": self.day,
+            "part": self.part.number(),
+            "answer": self.answer,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+        })
+        .to_string();
+    }
+
+    pub fn format(&self, format: OutputFormat) -> String {
+        return match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Json => self.to_json(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_result(answer: Option<&str>) -> PartResult {
+        return PartResult {
+            input: "day5.txt".to_string(),
+            day: 5,
+            part: Part::Two,
+            answer: answer.map(|answer| answer.to_string()),
+            elapsed: Duration::from_micros(1500),
+        };
+    }
+
+    #[test]
+    fn formats_text() {
+        assert_eq!(
+            get_result(Some("123")).format(OutputFormat::Text),
+            "Day 5 part 2: 123 (1.500ms)"
+        );
+        assert_eq!(
+            get_result(None).format(OutputFormat::Text),
+            "Day 5 part 2: not solved yet"
+        );
+    }
+
+    #[test]
+    fn formats_json() {
+        assert_eq!(
+            get_result(Some("123")).format(OutputFormat::Json),
+            r#"{"answer":"123","day":5,"elapsed_ms":1.5,"input":"day5.txt","part":2}"#
+        );
+        assert_eq!(
+            get_result(None).format(OutputFormat::Json),
+            r#"{"answer":null,"day":5,"elapsed_ms":1.5,"input":"day5.txt","part":2}"#
+        );
+    }
+}
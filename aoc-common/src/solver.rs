@@ -0,0 +1,480 @@
+use std::{
+    fmt,
+    io::{self, BufRead},
+    process,
+    time::Instant,
+};
+
+use clap::ValueEnum;
+use log::{error, info, warn};
+
+use crate::{
+    answers::{get_answer_key, AnswerStore, CheckOutcome},
+    bench::{BenchSummary, Phase},
+    cli::RunArgs,
+    error::{ParseError, SolveError},
+    file::{self, PuzzleInput, PuzzleInputs},
+    logging,
+    report::OutputFormat,
+    report::PartResult,
+};
+
+/// The puzzle answer for a single part, already formatted for display.
+pub type Answer = String;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl Part {
+    pub fn number(&self) -> u32 {
+        return match self {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+    }
+}
+
+/// A single day's solution: parse the raw input once, then answer each part from it.
+///
+/// Parts that haven't been solved yet return `None`.
+pub trait Solver {
+    type Input;
+
+    const DAY: u32;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(&self, input: &Self::Input) -> Option<Answer>;
+    fn part_two(&self, input: &Self::Input) -> Option<Answer>;
+
+    fn solve(&self, input: &Self::Input, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Solves a part that can fail even though its input parsed, such as when the answer doesn't
+    /// fit in the type it's computed in. Defaults to [`Solver::solve`], which can't fail.
+    fn try_solve(&self, input: &Self::Input, part: Part) -> Result<Option<Answer>, SolveError> {
+        return Ok(self.solve(input, part));
+    }
+}
+
+/// Solves a part of a named input and records how long it took.
+pub fn solve_timed<S: Solver>(
+    solver: &S,
+    input_name: &str,
+    input: &S::Input,
+    part: Part,
+) -> Result<PartResult, SolveError> {
+    let start = Instant::now();
+    let answer = solver.try_solve(input, part)?;
+    return Ok(PartResult {
+        input: input_name.to_string(),
+        day: S::DAY,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    });
+}
+
+/// Solves every puzzle input in turn, printing the answer and timing of the requested part, or of
+/// both parts, in the requested output format. Text output is headed by the input's name when
+/// there is more than one input.
+///
+/// With `--bench`, parsing and each part are repeated and their timings summarised after the answers.
+///
+/// Input that couldn't be read or fails to parse, and parts that fail to solve, are reported on
+/// stderr; once every input has been tried the process exits non-zero if any failed or, with
+/// `--check`, didn't match.
+pub fn run<S: Solver>(solver: &S, puzzle_inputs: &PuzzleInputs, args: &RunArgs) {
+    logging::init(args.verbose, args.quiet);
+    let mut succeeded = true;
+    for puzzle_input in puzzle_inputs {
+        let Some(puzzle_input) = get_readable_input(puzzle_input) else {
+            succeeded = false;
+            continue;
+        };
+        if puzzle_inputs.len() > 1 && args.format == OutputFormat::Text {
+            println!("==> {} <==", puzzle_input.name);
+        }
+        if !run_input(solver, puzzle_input, args) {
+            succeeded = false;
+        }
+    }
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// Like [`run`] for modes that print something other than the answers: every puzzle input is
+/// parsed with `parse` and handed to `report` along with what it parsed into.
+///
+/// Text output is headed by the input's name when there is more than one input. Input that
+/// couldn't be read or fails to parse is reported with a diagnostic on stderr, and once every
+/// input has been tried the process exits non-zero if any did.
+pub fn run_reports<T>(
+    puzzle_inputs: &PuzzleInputs,
+    args: &RunArgs,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+    mut report: impl FnMut(&PuzzleInput, T),
+) {
+    logging::init(args.verbose, args.quiet);
+    let mut succeeded = true;
+    for puzzle_input in puzzle_inputs {
+        let Some(puzzle_input) = get_readable_input(puzzle_input) else {
+            succeeded = false;
+            continue;
+        };
+        if puzzle_inputs.len() > 1 && args.format == OutputFormat::Text {
+            println!("==> {} <==", puzzle_input.name);
+        }
+        let parsed = match parse(&puzzle_input.data) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprint!("{}", err.render(&puzzle_input.name, &puzzle_input.data));
+                succeeded = false;
+                continue;
+            }
+        };
+        report(puzzle_input, parsed);
+    }
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// The puzzle input if it could be read, reporting it on stderr if not.
+fn get_readable_input(
+    puzzle_input: &Result<PuzzleInput, file::UnreadableInput>,
+) -> Option<&PuzzleInput> {
+    match puzzle_input {
+        Ok(puzzle_input) => return Some(puzzle_input),
+        Err(err) => {
+            eprintln!("{}", err);
+            return None;
+        }
+    }
+}
+
+/// Like [`run`] for days that can answer both parts in a single pass over a reader, so inputs
+/// never need to fit in memory; `solve` returns the answers to part one and part two, each of
+/// which may fail on its own, and each part is timed as the whole pass over its input.
+///
+/// Inputs that can't be opened or read and parts that fail to solve are reported on stderr, and
+/// once every input has been tried the process exits non-zero if any did.
+pub fn run_streaming<E: fmt::Display>(
+    day: u32,
+    file_paths: &Vec<String>,
+    args: &RunArgs,
+    mut solve: impl FnMut(
+        Box<dyn BufRead>,
+    ) -> Result<(Result<Answer, SolveError>, Result<Answer, SolveError>), E>,
+) {
+    logging::init(args.verbose, args.quiet);
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    let mut succeeded = true;
+    for file_path in file_paths {
+        let puzzle_reader = match file::open_puzzle_reader(file_path) {
+            Ok(puzzle_reader) => puzzle_reader,
+            Err(err) => {
+                eprintln!("Unable to read file '{}': {}", file_path, err);
+                succeeded = false;
+                continue;
+            }
+        };
+        let name = puzzle_reader.name;
+        if file_paths.len() > 1 && args.format == OutputFormat::Text {
+            println!("==> {} <==", name);
+        }
+        info!("Using file: {}", name);
+        let start = Instant::now();
+        let answers = match solve(puzzle_reader.reader) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("error: {}: {}", name, err);
+                succeeded = false;
+                continue;
+            }
+        };
+        let elapsed = start.elapsed();
+        for part in &parts {
+            let answer = match part {
+                Part::One => &answers.0,
+                Part::Two => &answers.1,
+            };
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("error: {}: part {}: {}", name, part, err);
+                    succeeded = false;
+                    continue;
+                }
+            };
+            let result = PartResult {
+                input: name.clone(),
+                day,
+                part: *part,
+                answer: Some(answer.clone()),
+                elapsed,
+            };
+            println!("{}", result.format(args.format));
+        }
+    }
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// Returns false if the input failed to parse, a part failed to solve, or the answers didn't match
+/// the recorded ones.
+fn run_input<S: Solver>(solver: &S, puzzle_input: &PuzzleInput, args: &RunArgs) -> bool {
+    info!("Using file: {}", puzzle_input.name);
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let runs = args.bench.unwrap_or(1);
+
+    let mut parse_samples = Vec::new();
+    let mut input = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = match solver.parse(&puzzle_input.data) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprint!("{}", err.render(&puzzle_input.name, &puzzle_input.data));
+                return false;
+            }
+        };
+        parse_samples.push(start.elapsed());
+        input = Some(parsed);
+    }
+    let input = input.expect("the input is parsed at least once");
+    info!("Day {} parse: ({:.3?})", S::DAY, parse_samples[0]);
+
+    let mut succeeded = true;
+    let mut results = Vec::new();
+    let mut summaries = vec![BenchSummary::from_samples(
+        &puzzle_input.name,
+        S::DAY,
+        Phase::Parse,
+        &parse_samples,
+    )];
+    for part in parts {
+        let mut part_samples = Vec::new();
+        let mut result = None;
+        for _ in 0..runs {
+            match solve_timed(solver, &puzzle_input.name, &input, part) {
+                Ok(solved) => {
+                    part_samples.push(solved.elapsed);
+                    result = Some(solved);
+                }
+                Err(err) => {
+                    eprintln!("error: {}: part {}: {}", puzzle_input.name, part, err);
+                    result = None;
+                    break;
+                }
+            }
+        }
+        let Some(result) = result else {
+            succeeded = false;
+            continue;
+        };
+        println!("{}", result.format(args.format));
+        summaries.push(BenchSummary::from_samples(
+            &puzzle_input.name,
+            S::DAY,
+            Phase::Solve(part),
+            &part_samples,
+        ));
+        results.push(result);
+    }
+
+    if args.bench.is_some() {
+        for summary in summaries {
+            println!("{}", summary.format(args.format));
+        }
+    }
+
+    if args.check || args.record {
+        let key = match get_answer_key(&puzzle_input.name) {
+            Ok(key) => key,
+            Err(err) => {
+                eprintln!("Unable to use answers for '{}': {}", puzzle_input.name, err);
+                return false;
+            }
+        };
+        if args.check {
+            let store = load_answer_store(&args.answers, AnswerStore::load);
+            if !check_results(&store, &key, &results) {
+                return false;
+            }
+        }
+        if args.record {
+            let store = load_answer_store(&args.answers, AnswerStore::load_or_default);
+            record_results(store, &args.answers, &key, &results);
+        }
+    }
+    return succeeded;
+}
+
+fn load_answer_store(path: &str, load: impl Fn(&str) -> io::Result<AnswerStore>) -> AnswerStore {
+    return match load(path) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("Unable to read answers file '{}': {}", path, err);
+            process::exit(1);
+        }
+    };
+}
+
+/// Logs how each result compares with the recorded answers; returns false if any differ or a
+/// solved part has no recorded answer.
+fn check_results(store: &AnswerStore, input_name: &str, results: &Vec<PartResult>) -> bool {
+    let mut all_match = true;
+    for result in results {
+        match store.check(input_name, result) {
+            CheckOutcome::Match => info!(
+                "Day {} part {}: matches the recorded answer",
+                result.day, result.part
+            ),
+            CheckOutcome::Mismatch { expected, actual } => {
+                error!(
+                    "Day {} part {}: expected {}, got {}",
+                    result.day,
+                    result.part,
+                    expected,
+                    actual.as_deref().unwrap_or("no answer")
+                );
+                all_match = false;
+            }
+            CheckOutcome::Unrecorded if result.answer.is_some() => {
+                error!(
+                    "Day {} part {}: no recorded answer for '{}'",
+                    result.day, result.part, input_name
+                );
+                all_match = false;
+            }
+            CheckOutcome::Unrecorded => warn!(
+                "Day {} part {}: not solved and no recorded answer for '{}'",
+                result.day, result.part, input_name
+            ),
+        }
+    }
+    return all_match;
+}
+
+fn record_results(mut store: AnswerStore, path: &str, input_name: &str, results: &Vec<PartResult>) {
+    for result in results {
+        store.record(input_name, result);
+    }
+    if let Err(err) = store.save(path) {
+        eprintln!("Unable to write answers file '{}': {}", path, err);
+        process::exit(1);
+    }
+    info!("Recorded answers in '{}'", path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solver for LineCount {
+        type Input = Vec<String>;
+
+        const DAY: u32 = 0;
+
+        fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+            Ok(raw_data.lines().map(|line| line.to_string()).collect())
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+            Some(input.len().to_string())
+        }
+
+        fn part_two(&self, _input: &Self::Input) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn solve_dispatches_on_part() {
+        let input = LineCount.parse("a\nb\nc").unwrap();
+        assert_eq!(LineCount.solve(&input, Part::One), Some("3".to_string()));
+        assert_eq!(LineCount.solve(&input, Part::Two), None);
+        assert_eq!(
+            LineCount.try_solve(&input, Part::One),
+            Ok(Some("3".to_string()))
+        );
+    }
+
+    #[test]
+    fn run_reports_hands_over_each_parsed_input() {
+        let puzzle_inputs = vec![
+            Ok(PuzzleInput {
+                name: "a.txt".to_string(),
+                data: "a\nb".to_string(),
+            }),
+            Ok(PuzzleInput {
+                name: "b.txt".to_string(),
+                data: "c".to_string(),
+            }),
+        ];
+        let mut reports = Vec::new();
+        let parse = |data: &str| LineCount.parse(data);
+        run_reports(
+            &puzzle_inputs,
+            &RunArgs::default(),
+            parse,
+            |puzzle_input, lines| reports.push((puzzle_input.name.clone(), lines.len())),
+        );
+        assert_eq!(
+            reports,
+            vec![("a.txt".to_string(), 2), ("b.txt".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn checking_fails_for_unrecorded_answers() {
+        let store = AnswerStore::from_toml(
+            "[[answers]]\nday = 0\npart = 1\ninput = \"lines.txt\"\nanswer = \"2\"\n",
+        )
+        .unwrap();
+        let input = LineCount.parse("a\nb").unwrap();
+        let one = solve_timed(&LineCount, "lines.txt", &input, Part::One).unwrap();
+        let two = solve_timed(&LineCount, "lines.txt", &input, Part::Two).unwrap();
+        assert!(check_results(&store, "lines.txt", &vec![one.clone(), two]));
+        assert!(!check_results(&store, "other.txt", &vec![one]));
+    }
+
+    #[test]
+    fn solve_timed_records_day_and_part() {
+        let input = LineCount.parse("a\nb").unwrap();
+        let result = solve_timed(&LineCount, "lines.txt", &input, Part::One).unwrap();
+        assert_eq!(result.input, "lines.txt");
+        assert_eq!(result.day, 0);
+        assert_eq!(result.part, Part::One);
+        assert_eq!(result.answer, Some("2".to_string()));
+    }
+}
@@ -0,0 +1,73 @@
+use std::process;
+
+use aoc_common::{
+    cli::{InputArgs, RunArgs},
+    file::PuzzleInputs,
+    solver,
+};
+use clap::{Parser, Subcommand};
+
+/// Days with a solver, in the order they are listed.
+const DAYS: [u32; 14] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 17];
+
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle, printing each part's answer and timing.
+    Run {
+        #[arg(short, long, value_name = "DAY")]
+        day: u32,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        run: RunArgs,
+    },
+    /// List the days that have a solver.
+    List,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, input, run } => {
+            if !DAYS.contains(&day) {
+                eprintln!("There is no solver for day {}", day);
+                process::exit(1);
+            }
+            let puzzle_inputs = input.load();
+            run_day(day, &puzzle_inputs, &run);
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("{}", day);
+            }
+        }
+    }
+}
+
+/// Dispatches to the solver for `day`, using each day's default puzzle rules.
+fn run_day(day: u32, puzzle_inputs: &PuzzleInputs, args: &RunArgs) {
+    match day {
+        1 => solver::run(&day1::Day1::default(), puzzle_inputs, args),
+        2 => solver::run(&day2::Day2::default(), puzzle_inputs, args),
+        3 => solver::run(&day3::Day3::default(), puzzle_inputs, args),
+        4 => solver::run(&day4::Day4::default(), puzzle_inputs, args),
+        5 => solver::run(&day5::Day5, puzzle_inputs, args),
+        6 => solver::run(&day6::Day6, puzzle_inputs, args),
+        7 => solver::run(&day7::Day7, puzzle_inputs, args),
+        8 => solver::run(&day8::Day8, puzzle_inputs, args),
+        9 => solver::run(&day9::Day9, puzzle_inputs, args),
+        10 => solver::run(&day10::Day10, puzzle_inputs, args),
+        11 => solver::run(&day11::Day11::default(), puzzle_inputs, args),
+        12 => solver::run(&day12::Day12, puzzle_inputs, args),
+        13 => solver::run(&day13::Day13::default(), puzzle_inputs, args),
+        17 => solver::run(&day17::Day17, puzzle_inputs, args),
+        _ => unreachable!("day {} is not in DAYS", day),
+    }
+}
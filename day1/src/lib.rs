@@ -0,0 +1,439 @@
+use std::{collections::HashMap, io::BufRead};
+
+use aoc_common::{
+    error::{parse_token, ParseError},
+    file::ReadError,
+    solver::{Answer, Solver},
+};
+use clap::ValueEnum;
+use log::{info, trace};
+
+pub mod metrics;
+
+/// How the columns of each row are separated.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Separator {
+    /// Any run of spaces or tabs.
+    #[default]
+    Whitespace,
+    /// Commas, with optional whitespace around each value.
+    Csv,
+    /// Single tabs, with optional whitespace around each value.
+    Tsv,
+}
+
+impl Separator {
+    pub fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        return match self {
+            Separator::Whitespace => line.split_whitespace().collect(),
+            Separator::Csv => line.split(',').map(|field| field.trim()).collect(),
+            Separator::Tsv => line.split('\t').map(|field| field.trim()).collect(),
+        };
+    }
+}
+
+/// Both columns of location IDs, each sorted ascending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    pub list_a: Vec<i64>,
+    pub list_b: Vec<i64>,
+}
+
+/// Parses rows of exactly two location IDs; blank lines are skipped and any other row is an error.
+pub fn parse_location_lists(
+    raw_data: &str,
+    separator: Separator,
+) -> Result<LocationLists, ParseError> {
+    let mut list_a = Vec::new();
+    let mut list_b = Vec::new();
+    for line in raw_data.lines() {
+        if let Some((a, b)) = parse_location_pair(raw_data, line, separator)? {
+            list_a.push(a);
+            list_b.push(b);
+        }
+    }
+    list_a.sort();
+    list_b.sort();
+    return Ok(LocationLists { list_a, list_b });
+}
+
+/// Parses any number of columns of location IDs, each sorted ascending.
+///
+/// The first row sets how many columns there are; blank lines are skipped and a row with a
+/// different number of IDs is an error.
+pub fn parse_location_columns(
+    raw_data: &str,
+    separator: Separator,
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+    for line in raw_data.lines() {
+        let column_count = if columns.is_empty() {
+            separator.split(line).len()
+        } else {
+            columns.len()
+        };
+        let Some(row) = parse_location_row(raw_data, line, separator, column_count)? else {
+            continue;
+        };
+        if columns.is_empty() {
+            columns = vec![Vec::new(); column_count];
+        }
+        for (column, id) in columns.iter_mut().zip(row) {
+            column.push(id);
+        }
+    }
+    for column in columns.iter_mut() {
+        column.sort();
+    }
+    return Ok(columns);
+}
+
+/// Parses one row of `raw_data`, returning `None` for a blank line.
+fn parse_location_row(
+    raw_data: &str,
+    line: &str,
+    separator: Separator,
+    column_count: usize,
+) -> Result<Option<Vec<i64>>, ParseError> {
+    trace!("{}", line);
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    let columns = separator.split(line);
+    if columns.len() != column_count {
+        return Err(ParseError::at(
+            raw_data,
+            line,
+            format!(
+                "expected {} location IDs, found {}",
+                column_count,
+                columns.len()
+            ),
+        ));
+    }
+    let row = columns
+        .iter()
+        .map(|id| parse_token::<i64>(raw_data, id))
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+    return Ok(Some(row));
+}
+
+/// Parses a row of exactly two location IDs, returning `None` for a blank line.
+fn parse_location_pair(
+    raw_data: &str,
+    line: &str,
+    separator: Separator,
+) -> Result<Option<(i64, i64)>, ParseError> {
+    return Ok(parse_location_row(raw_data, line, separator, 2)?.map(|row| (row[0], row[1])));
+}
+
+/// How often each location ID appears in both lists, fed one row at a time.
+///
+/// Only distinct IDs are kept, so both totals can be found without holding every row in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocationTally {
+    list_a_counts: HashMap<i64, i64>,
+    list_b_counts: HashMap<i64, i64>,
+}
+
+impl LocationTally {
+    pub fn add_row(&mut self, a: i64, b: i64) {
+        *self.list_a_counts.entry(a).or_insert(0) += 1;
+        *self.list_b_counts.entry(b).or_insert(0) += 1;
+    }
+
+    pub fn similarity_score(&self) -> i64 {
+        return get_similarity_score_from_counts(&self.list_a_counts, &self.list_b_counts);
+    }
+
+    /// Pairs the sorted lists by walking both sets of distinct IDs in order.
+    pub fn total_distance(&self) -> i64 {
+        let list_a = get_sorted_counts(&self.list_a_counts);
+        let list_b = get_sorted_counts(&self.list_b_counts);
+        let mut total_distance = 0;
+        let (mut i, mut j) = (0, 0);
+        let (mut remaining_a, mut remaining_b) = (0, 0);
+        while i < list_a.len() && j < list_b.len() {
+            if remaining_a == 0 {
+                remaining_a = list_a[i].1;
+            }
+            if remaining_b == 0 {
+                remaining_b = list_b[j].1;
+            }
+            let pairs = remaining_a.min(remaining_b);
+            total_distance += (list_a[i].0 - list_b[j].0).abs() * pairs;
+            remaining_a -= pairs;
+            remaining_b -= pairs;
+            if remaining_a == 0 {
+                i += 1;
+            }
+            if remaining_b == 0 {
+                j += 1;
+            }
+        }
+        return total_distance;
+    }
+}
+
+/// Both puzzle answers for a set of location lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocationTotals {
+    pub total_distance: i64,
+    pub similarity_score: i64,
+}
+
+/// Tallies rows from `reader` a line at a time, so large exports never need to fit in memory.
+pub fn get_location_tally_from_reader<R: BufRead>(
+    mut reader: R,
+    separator: Separator,
+) -> Result<LocationTally, ReadError<ParseError>> {
+    let mut tally = LocationTally::default();
+    let mut line = String::new();
+    let mut line_number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        line_number += 1;
+        let row = line.trim_end_matches(['\n', '\r']);
+        match parse_location_pair(row, row, separator) {
+            Ok(Some((a, b))) => tally.add_row(a, b),
+            Ok(None) => {}
+            Err(mut err) => {
+                err.line = line_number;
+                return Err(ReadError::Input(err));
+            }
+        }
+        line.clear();
+    }
+    return Ok(tally);
+}
+
+/// Computes both totals in a single pass over `reader`.
+pub fn get_location_totals_from_reader<R: BufRead>(
+    reader: R,
+    separator: Separator,
+) -> Result<LocationTotals, ReadError<ParseError>> {
+    let tally = get_location_tally_from_reader(reader, separator)?;
+    return Ok(LocationTotals {
+        total_distance: tally.total_distance(),
+        similarity_score: tally.similarity_score(),
+    });
+}
+
+/// The sum of the distances between the smallest IDs of each list, then the next smallest, and so on.
+pub fn total_distance(lists: &LocationLists) -> i64 {
+    return get_total_distance(&lists.list_a, &lists.list_b);
+}
+
+/// The sum of each ID in the first list multiplied by how often it appears in the second.
+pub fn similarity_score(lists: &LocationLists) -> i64 {
+    return get_total_similarity_score(&lists.list_a, &lists.list_b);
+}
+
+#[derive(Default)]
+pub struct Day1 {
+    pub separator: Separator,
+}
+
+impl Solver for Day1 {
+    type Input = LocationTally;
+
+    const DAY: u32 = 1;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let mut tally = LocationTally::default();
+        for line in raw_data.lines() {
+            if let Some((a, b)) = parse_location_pair(raw_data, line, self.separator)? {
+                tally.add_row(a, b);
+            }
+        }
+        return Ok(tally);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let total_distance = input.total_distance();
+        info!("Total distance {}", total_distance);
+        return Some(total_distance.to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let total_similarity_score = input.similarity_score();
+        info!("Total Similarity Score {}", total_similarity_score);
+        return Some(total_similarity_score.to_string());
+    }
+}
+
+pub(crate) fn get_total_distance(list_a: &Vec<i64>, list_b: &Vec<i64>) -> i64 {
+    let mut list_c = Vec::new();
+    let mut total_distance = 0;
+    for i in 0..list_a.len() {
+        if list_a[i] > list_b[i] {
+            list_c.push(list_a[i] - list_b[i]);
+        } else {
+            list_c.push(list_b[i] - list_a[i]);
+        }
+    }
+
+    for num in list_c {
+        total_distance += num;
+    }
+    return total_distance;
+}
+
+fn get_total_similarity_score(list_a: &Vec<i64>, list_b: &Vec<i64>) -> i64 {
+    return get_similarity_score_from_counts(&get_counts(list_a), &get_counts(list_b));
+}
+
+pub(crate) fn get_counts(list: &Vec<i64>) -> HashMap<i64, i64> {
+    let mut counts = HashMap::new();
+    for &num in list {
+        *counts.entry(num).or_insert(0) += 1;
+    }
+    return counts;
+}
+
+/// Each occurrence of an ID in the first list scores the ID times its count in the second.
+pub(crate) fn get_similarity_score_from_counts(
+    list_a_counts: &HashMap<i64, i64>,
+    list_b_counts: &HashMap<i64, i64>,
+) -> i64 {
+    let mut total_similarity_score = 0;
+    for (num, count_a) in list_a_counts {
+        if let Some(count_b) = list_b_counts.get(num) {
+            total_similarity_score += num * count_a * count_b;
+        }
+    }
+    return total_similarity_score;
+}
+
+fn get_sorted_counts(counts: &HashMap<i64, i64>) -> Vec<(i64, i64)> {
+    let mut sorted: Vec<(i64, i64)> = counts.iter().map(|(num, count)| (*num, *count)).collect();
+    sorted.sort();
+    return sorted;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn parse_sorts_both_lists() {
+        let lists = parse_location_lists(TEST_CASE, Separator::Whitespace).unwrap();
+        assert_eq!(lists.list_a, vec![1, 2, 3, 3, 3, 4]);
+        assert_eq!(lists.list_b, vec![3, 3, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn total_distance_matches_sample() {
+        let lists = parse_location_lists(TEST_CASE, Separator::Whitespace).unwrap();
+        assert_eq!(total_distance(&lists), 11);
+    }
+
+    #[test]
+    fn similarity_score_matches_sample() {
+        let lists = parse_location_lists(TEST_CASE, Separator::Whitespace).unwrap();
+        assert_eq!(similarity_score(&lists), 31);
+    }
+
+    #[test]
+    fn tally_matches_sorted_lists() {
+        let tally = Day1::default().parse(TEST_CASE).unwrap();
+        assert_eq!(tally.total_distance(), 11);
+        assert_eq!(tally.similarity_score(), 31);
+
+        let raw_data = "5 1\n5 9\n1 5\n7 5\n5 5\n";
+        let lists = parse_location_lists(raw_data, Separator::Whitespace).unwrap();
+        let tally = Day1::default().parse(raw_data).unwrap();
+        assert_eq!(tally.total_distance(), total_distance(&lists));
+        assert_eq!(tally.similarity_score(), similarity_score(&lists));
+    }
+
+    #[test]
+    fn reader_computes_both_totals() {
+        let totals =
+            get_location_totals_from_reader(TEST_CASE.as_bytes(), Separator::Whitespace).unwrap();
+        assert_eq!(
+            totals,
+            LocationTotals {
+                total_distance: 11,
+                similarity_score: 31
+            }
+        );
+    }
+
+    #[test]
+    fn reader_reports_line_of_bad_row() {
+        let err = get_location_totals_from_reader("1,2\r\n3,x\r\n".as_bytes(), Separator::Csv)
+            .unwrap_err();
+        match err {
+            ReadError::Input(err) => {
+                assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"))
+            }
+            ReadError::Io(err) => panic!("unexpected io error: {}", err),
+        }
+    }
+
+    #[test]
+    fn parses_every_separator() {
+        let expected = parse_location_lists(TEST_CASE, Separator::Whitespace).unwrap();
+        let csv = TEST_CASE.replace("   ", ", ");
+        assert_eq!(
+            parse_location_lists(&csv, Separator::Csv).unwrap(),
+            expected
+        );
+        let tsv = TEST_CASE.replace("   ", "\t");
+        assert_eq!(
+            parse_location_lists(&tsv, Separator::Tsv).unwrap(),
+            expected
+        );
+        let mixed = TEST_CASE.replace("   ", " \t ");
+        assert_eq!(
+            parse_location_lists(&mixed, Separator::Whitespace).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn skips_blank_lines() {
+        let lists = parse_location_lists("3   4\n\n4   3\n", Separator::Whitespace).unwrap();
+        assert_eq!(lists.list_a, vec![3, 4]);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = parse_location_lists("3   4\n5\n", Separator::Whitespace).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 2 location IDs, found 1");
+
+        let err = parse_location_lists("3   4\n5   6   7\n", Separator::Whitespace).unwrap_err();
+        assert_eq!(err.message, "expected 2 location IDs, found 3");
+
+        assert!(parse_location_lists("3,4\n5,6,\n", Separator::Csv).is_err());
+    }
+
+    #[test]
+    fn parses_any_number_of_columns() {
+        let columns =
+            parse_location_columns("3 4 9\n\n1 2 7\n5 0 8\n", Separator::Whitespace).unwrap();
+        assert_eq!(columns, vec![vec![1, 3, 5], vec![0, 2, 4], vec![7, 8, 9]]);
+        assert_eq!(
+            parse_location_columns("", Separator::Whitespace).unwrap(),
+            Vec::<Vec<i64>>::new()
+        );
+
+        let err = parse_location_columns("3 4 9\n1 2\n", Separator::Whitespace).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected 3 location IDs, found 2");
+    }
+
+    #[test]
+    fn rejects_separator_mismatch() {
+        assert!(parse_location_lists(TEST_CASE, Separator::Csv).is_err());
+    }
+
+    #[test]
+    fn points_at_invalid_ids() {
+        let err = parse_location_lists("3,4\n5, x6\n", Separator::Csv).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
@@ -1,68 +1,78 @@
-use std::fs;
+use aoc_common::{
+    cli::{InputArgs, RunArgs},
+    report::OutputFormat,
+    solver::{self, Solver},
+};
+use clap::Parser;
+use day1::{
+    get_location_totals_from_reader,
+    metrics::{get_metric_matrix, get_metric_matrix_json, render_metric_matrix, Metric},
+    parse_location_columns, Day1, Separator,
+};
 
-struct Similarity{
-    list_a_number: i32,
-    list_b_count: i32
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    run: RunArgs,
+    /// How the two columns of location IDs are separated.
+    #[arg(short, long, value_enum, value_name = "SEPARATOR", default_value_t)]
+    separator: Separator,
+    /// Read each file a line at a time, finding both answers in one pass without loading it whole.
+    #[arg(long, conflicts_with_all = ["bench", "check", "record"])]
+    stream: bool,
+    /// Compare every column of location IDs with every other using this metric, printing a table
+    /// instead of the puzzle answers; any number of columns is allowed and the flag may be repeated.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_name = "METRIC",
+        conflicts_with_all = ["stream", "part", "bench", "check", "record"]
+    )]
+    metric: Vec<Metric>,
 }
 
 fn main() {
-    let file_path = "./source_data/input.txt";
-    //let file_path = "./source_data/test_case.txt";
-    let data = Box::new(load_input(&file_path));
-    let mut list_a = Vec::new();
-    let mut list_b = Vec::new();
-    let mut list_c = Vec::new();
-
-    let mut similarity_scores: Vec<Similarity> = Vec::new();
-
-    let mut total_distance = 0;
-    let mut total_similarity_score = 0;
-    for line in data.lines() {
-        println!("{}", line);
-       let temp_line: Vec<i32> = line.split(' ').filter(|str| !str.is_empty()).map(|num| num.trim().parse::<i32>().unwrap()).collect();
-       println!("{:?}", temp_line);
-       if temp_line.len() > 4 {
-        println!("Something went wrong and there are too many substrings per line...");
-       }
-       list_a.push(temp_line[0]);
-       list_b.push(temp_line[1]);
+    let cli = Cli::parse();
+    if !cli.metric.is_empty() {
+        print_metric_tables(&cli);
+        return;
     }
-
-    // Part 1
-    list_a.sort();
-    list_b.sort();
-    for i in 0..list_a.len() { 
-        if list_a[i] > list_b[i] {
-            list_c.push(list_a[i] - list_b[i]);
-        }
-        else {
-            list_c.push(list_b[i] - list_a[i]);
-        }
+    if cli.stream {
+        solver::run_streaming(Day1::DAY, &cli.input.files, &cli.run, |reader| {
+            get_location_totals_from_reader(reader, cli.separator).map(|totals| {
+                (
+                    Ok(totals.total_distance.to_string()),
+                    Ok(totals.similarity_score.to_string()),
+                )
+            })
+        });
+        return;
     }
-
-    for num in list_c {
-        total_distance += num;
-    }
-    println!("Total distance {}", total_distance);
-
-
-
-    // Part 2
-    for num in list_a {
-        let count = list_b.iter().filter(|&&numb| numb == num).count() as i32;
-        similarity_scores.push(Similarity {list_a_number: num, list_b_count: count});
-    }
-
-    for score in similarity_scores{
-        total_similarity_score += score.list_a_number * score.list_b_count
-    }
-    println!("Total Similarity Score {}", total_similarity_score);
-
-
-    
+    let day1 = Day1 {
+        separator: cli.separator,
+    };
+    let puzzle_inputs = cli.input.load();
+    solver::run(&day1, &puzzle_inputs, &cli.run);
 }
 
-fn load_input(file_path: &str) -> String {
-    return fs::read_to_string(file_path).expect("Unable to read file");
+fn print_metric_tables(cli: &Cli) {
+    let puzzle_inputs = cli.input.load();
+    let parse = |data: &str| parse_location_columns(data, cli.separator);
+    solver::run_reports(&puzzle_inputs, &cli.run, parse, |puzzle_input, columns| {
+        for metric in &cli.metric {
+            let matrix = get_metric_matrix(*metric, &columns);
+            match cli.run.format {
+                OutputFormat::Text => print!("{}", render_metric_matrix(*metric, &matrix)),
+                OutputFormat::Json => {
+                    for record in get_metric_matrix_json(&puzzle_input.name, *metric, &matrix) {
+                        println!("{}", record);
+                    }
+                }
+            }
+        }
+    });
 }
-
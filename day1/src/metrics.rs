@@ -0,0 +1,223 @@
+use std::{collections::HashSet, fmt};
+
+use clap::ValueEnum;
+use serde_json::json;
+
+use crate::{get_counts, get_similarity_score_from_counts, get_total_distance};
+
+/// A way of comparing two location lists.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the distances between the lists once both are sorted.
+    L1,
+    /// Each ID in the first list times how often it appears in the second.
+    Similarity,
+    /// Distinct IDs in both lists over distinct IDs in either.
+    Jaccard,
+    /// How many IDs in the first list have no counterpart left in the second.
+    MultisetDifference,
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Metric::L1 => write!(f, "l1"),
+            Metric::Similarity => write!(f, "similarity"),
+            Metric::Jaccard => write!(f, "jaccard"),
+            Metric::MultisetDifference => write!(f, "multiset-difference"),
+        }
+    }
+}
+
+/// The result of comparing two lists; ratios are shown to 4 decimal places.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricValue {
+    Count(i64),
+    Ratio(f64),
+}
+
+impl fmt::Display for MetricValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetricValue::Count(count) => write!(f, "{}", count),
+            MetricValue::Ratio(ratio) => write!(f, "{:.4}", ratio),
+        }
+    }
+}
+
+/// Compares `list_a` with `list_b`, which must both be sorted.
+///
+/// Similarity and multiset difference aren't symmetric, so the order of the lists matters.
+pub fn get_metric_value(metric: Metric, list_a: &Vec<i64>, list_b: &Vec<i64>) -> MetricValue {
+    return match metric {
+        Metric::L1 => MetricValue::Count(get_total_distance(list_a, list_b)),
+        Metric::Similarity => MetricValue::Count(get_similarity_score_from_counts(
+            &get_counts(list_a),
+            &get_counts(list_b),
+        )),
+        Metric::Jaccard => {
+            let set_a: HashSet<&i64> = list_a.iter().collect();
+            let set_b: HashSet<&i64> = list_b.iter().collect();
+            let union = set_a.union(&set_b).count();
+            if union == 0 {
+                MetricValue::Ratio(1.0)
+            } else {
+                MetricValue::Ratio(set_a.intersection(&set_b).count() as f64 / union as f64)
+            }
+        }
+        Metric::MultisetDifference => {
+            let counts_b = get_counts(list_b);
+            let mut difference = 0;
+            for (id, count_a) in get_counts(list_a) {
+                difference += (count_a - counts_b.get(&id).unwrap_or(&0)).max(0);
+            }
+            MetricValue::Count(difference)
+        }
+    };
+}
+
+/// Compares every column with every other; row `i`, column `j` holds `metric(columns[i], columns[j])`.
+pub fn get_metric_matrix(metric: Metric, columns: &Vec<Vec<i64>>) -> Vec<Vec<MetricValue>> {
+    return columns
+        .iter()
+        .map(|list_a| {
+            columns
+                .iter()
+                .map(|list_b| get_metric_value(metric, list_a, list_b))
+                .collect()
+        })
+        .collect();
+}
+
+/// Lays the matrix out as a table with right-aligned cells, numbering the lists from 1.
+pub fn render_metric_matrix(metric: Metric, matrix: &Vec<Vec<MetricValue>>) -> String {
+    let mut rows = vec![vec![metric.to_string()]];
+    rows[0].extend((1..=matrix.len()).map(|i| i.to_string()));
+    for (i, values) in matrix.iter().enumerate() {
+        let mut row = vec![(i + 1).to_string()];
+        row.extend(values.iter().map(|value| value.to_string()));
+        rows.push(row);
+    }
+
+    let mut widths = vec![0; rows[0].len()];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut rendered = String::new();
+    for row in &rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect();
+        rendered.push_str(cells.join("  ").trim_end());
+        rendered.push('\n');
+    }
+    return rendered;
+}
+
+/// One JSON record per cell: `{"input", "metric", "from", "to", "value"}`, with lists numbered from 1.
+pub fn get_metric_matrix_json(
+    input: &str,
+    metric: Metric,
+    matrix: &Vec<Vec<MetricValue>>,
+) -> Vec<String> {
+    let mut records = Vec::new();
+    for (i, values) in matrix.iter().enumerate() {
+        for (j, value) in values.iter().enumerate() {
+            let value = match value {
+                MetricValue::Count(count) => json!(count),
+                MetricValue::Ratio(ratio) => json!(ratio),
+            };
+            records.push(
+                json!({
+                    "input": input,
+                    "metric": metric.to_string(),
+                    "from": i + 1,
+                    "to": j + 1,
+                    "value": value,
+                })
+                .to_string(),
+            );
+        }
+    }
+    return records;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_columns() -> Vec<Vec<i64>> {
+        return vec![
+            vec![1, 2, 3, 3, 3, 4],
+            vec![3, 3, 3, 4, 5, 9],
+            vec![1, 2, 3, 3, 3, 4],
+        ];
+    }
+
+    #[test]
+    fn metrics_match_sample() {
+        let columns = get_columns();
+        assert_eq!(
+            get_metric_value(Metric::L1, &columns[0], &columns[1]),
+            MetricValue::Count(11)
+        );
+        assert_eq!(
+            get_metric_value(Metric::Similarity, &columns[0], &columns[1]),
+            MetricValue::Count(31)
+        );
+        assert_eq!(
+            get_metric_value(Metric::Jaccard, &columns[0], &columns[1]),
+            MetricValue::Ratio(2.0 / 6.0)
+        );
+        assert_eq!(
+            get_metric_value(Metric::MultisetDifference, &columns[0], &columns[1]),
+            MetricValue::Count(2)
+        );
+        assert_eq!(
+            get_metric_value(Metric::MultisetDifference, &columns[1], &columns[0]),
+            MetricValue::Count(2)
+        );
+    }
+
+    #[test]
+    fn identical_lists_have_no_distance() {
+        let columns = get_columns();
+        let matrix = get_metric_matrix(Metric::L1, &columns);
+        assert_eq!(matrix[0][2], MetricValue::Count(0));
+        assert_eq!(matrix[1][1], MetricValue::Count(0));
+        assert_eq!(
+            get_metric_matrix(Metric::Jaccard, &columns)[0][2],
+            MetricValue::Ratio(1.0)
+        );
+    }
+
+    #[test]
+    fn renders_table() {
+        let matrix = get_metric_matrix(Metric::L1, &get_columns());
+        assert_eq!(
+            render_metric_matrix(Metric::L1, &matrix),
+            "l1   1   2   3\n 1   0  11   0\n 2  11   0  11\n 3   0  11   0\n"
+        );
+        let matrix = get_metric_matrix(Metric::Jaccard, &get_columns()[..2].to_vec());
+        assert_eq!(
+            render_metric_matrix(Metric::Jaccard, &matrix),
+            "jaccard       1       2\n      1  1.0000  0.3333\n      2  0.3333  1.0000\n"
+        );
+    }
+
+    #[test]
+    fn json_has_a_record_per_cell() {
+        let matrix = get_metric_matrix(Metric::Similarity, &get_columns()[..2].to_vec());
+        let records = get_metric_matrix_json("day1.txt", Metric::Similarity, &matrix);
+        assert_eq!(records.len(), 4);
+        assert_eq!(
+            records[1],
+            r#"{"from":1,"input":"day1.txt","metric":"similarity","to":2,"value":31}"#
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use log::info;
+use std::{
+    cell::RefCell,
+    collections::{HashSet, VecDeque},
+    fmt,
+    rc::Rc,
+};
+
+use aoc_common::{
+    error::ParseError,
+    grid::check_rectangular,
+    solver::{Answer, Solver},
+};
+
+pub type PathNodeRef = Rc<RefCell<PathNode>>;
+
+#[derive(Debug, Clone)]
+pub struct PathNode {
+    val: u32,
+    up: Option<PathNodeRef>,
+    down: Option<PathNodeRef>,
+    left: Option<PathNodeRef>,
+    right: Option<PathNodeRef>,
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<PathNodeRef>;
+
+    const DAY: u32 = 10;
+
+    /// Builds the height map graph and returns its trailheads.
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let nodes = generate_nodes_from_file(raw_data)?;
+        let trailheads: Vec<Rc<RefCell<PathNode>>> = nodes
+            .iter()
+            .filter(|n| n.borrow().val == 0)
+            .cloned()
+            .collect();
+        return Ok(trailheads);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_total_score(input).to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_total_rating(input).to_string());
+    }
+}
+
+fn get_total_score(trailheads: &Vec<Rc<RefCell<PathNode>>>) -> u32 {
+    let mut bfs_sum = 0;
+    for trailhead in trailheads {
+        let target_count = get_score_unique_destinations(trailhead.clone(), 9);
+        //println!("Target Count: {:?}\n\n", target_count);
+        bfs_sum += target_count;
+    }
+    info!("Total score: {}", bfs_sum);
+    return bfs_sum;
+}
+fn get_total_rating(trailheads: &Vec<Rc<RefCell<PathNode>>>) -> u32 {
+    let mut dfs_sum = 0;
+    for trailhead in trailheads {
+        let target_count = get_all_possible_path_score(trailhead.clone(), 9);
+        //println!("Target Count: {:?}\n\n", target_count);
+        dfs_sum += target_count;
+    }
+    info!("Total score: {}", dfs_sum);
+    return dfs_sum;
+}
+
+fn get_all_possible_path_score(trailhead: PathNodeRef, target: u32) -> u32 {
+    let mut target_count = 0;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(trailhead);
+
+    while let Some(node) = queue.pop_front() {
+        //println!("Current Node: {:#?}", node.borrow().val);
+        if node.borrow().val == target {
+            target_count += 1;
+            continue;
+        }
+
+        // Insert node to visited list
+
+        let items = node.borrow();
+        if let Some(up) = &items.up {
+            if up.borrow().val == node.borrow().val + 1 {
+                //println!("Up: {}", up.borrow().val);
+                queue.push_front(up.clone());
+            }
+        }
+        if let Some(down) = &items.down {
+            if down.borrow().val == node.borrow().val + 1 {
+                //println!("Down: {}", down.borrow().val);
+                queue.push_front(down.clone());
+            }
+        }
+        if let Some(left) = &items.left {
+            if left.borrow().val == node.borrow().val + 1 {
+                //println!("Left: {}", left.borrow().val);
+                queue.push_front(left.clone());
+            }
+        }
+        if let Some(right) = &items.right {
+            if right.borrow().val == node.borrow().val + 1 {
+                // println!("Right: {}", right.borrow().val);
+                queue.push_front(right.clone());
+            }
+        }
+    }
+    return target_count;
+}
+fn get_score_unique_destinations(trailhead: PathNodeRef, target: u32) -> u32 {
+    let mut visited = HashSet::new();
+    let mut target_count = 0;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(trailhead);
+
+    while let Some(node) = queue.pop_front() {
+        if visited.contains(&Rc::as_ptr(&node)) {
+            continue;
+        }
+        //println!("Current Node: {:#?}", node.borrow().val);
+        // Insert node to visited list
+        visited.insert(Rc::as_ptr(&node));
+
+        if node.borrow().val == target {
+            target_count += 1;
+            continue;
+        }
+
+        let items = node.borrow();
+        if let Some(up) = &items.up {
+            if up.borrow().val == node.borrow().val + 1 {
+                //println!("Up: {}", up.borrow().val);
+                queue.push_front(up.clone());
+            }
+        }
+        if let Some(down) = &items.down {
+            if down.borrow().val == node.borrow().val + 1 {
+                //println!("Down: {}", down.borrow().val);
+                queue.push_front(down.clone());
+            }
+        }
+        if let Some(left) = &items.left {
+            if left.borrow().val == node.borrow().val + 1 {
+                //println!("Left: {}", left.borrow().val);
+                queue.push_front(left.clone());
+            }
+        }
+        if let Some(right) = &items.right {
+            if right.borrow().val == node.borrow().val + 1 {
+                // println!("Right: {}", right.borrow().val);
+                queue.push_front(right.clone());
+            }
+        }
+    }
+    return target_count;
+}
+
+fn generate_nodes_from_file(raw_data: &str) -> Result<Vec<PathNodeRef>, ParseError> {
+    check_rectangular(raw_data)?;
+    let mut nodes = Vec::new();
+
+    for line in raw_data.lines() {
+        let mut temp = Vec::new();
+        for (byte_index, height) in line.char_indices() {
+            let val = height.to_digit(10).ok_or_else(|| {
+                ParseError::at(
+                    raw_data,
+                    &line[byte_index..byte_index + height.len_utf8()],
+                    "expected a height from 0 to 9",
+                )
+            })?;
+            temp.push(Rc::new(RefCell::new(PathNode {
+                val,
+                up: None,
+                down: None,
+                left: None,
+                right: None,
+            })))
+        }
+        nodes.push(temp);
+    }
+
+    // add edges
+    for x in 0..nodes.len() {
+        for y in 0..nodes[x].len() {
+            nodes[x][y].borrow_mut().up = if x > 0 {
+                Some(nodes[x - 1][y].clone())
+            } else {
+                None
+            };
+            nodes[x][y].borrow_mut().down = if x < nodes.len() - 1 {
+                Some(nodes[x + 1][y].clone())
+            } else {
+                None
+            };
+            nodes[x][y].borrow_mut().right = if y < nodes[x].len() - 1 {
+                Some(nodes[x][y + 1].clone())
+            } else {
+                None
+            };
+            nodes[x][y].borrow_mut().left = if y > 0 {
+                Some(nodes[x][y - 1].clone())
+            } else {
+                None
+            };
+            //println!("\n\nNode: {:#?}", nodes[x][y]);
+        }
+    }
+
+    return Ok(nodes.into_iter().flatten().collect());
+}
+
+impl fmt::Display for PathNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.val)
+        // or, alternatively:
+        // fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn finds_every_trailhead() {
+        let trailheads = Day10.parse(TEST_CASE).unwrap();
+        assert_eq!(trailheads.len(), 9);
+    }
+
+    #[test]
+    fn sample_answers() {
+        let trailheads = Day10.parse(TEST_CASE).unwrap();
+        assert_eq!(get_total_score(&trailheads), 36);
+        assert_eq!(get_total_rating(&trailheads), 81);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Day10.parse("012\n0\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "0"));
+    }
+
+    #[test]
+    fn parse_locates_invalid_heights() {
+        let err = Day10
+            .parse(
+                "0123
+12é4
+",
+            )
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "é"));
+    }
+}
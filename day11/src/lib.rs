@@ -0,0 +1,135 @@
+use log::info;
+use std::collections::HashMap;
+
+use aoc_common::{
+    error::{parse_token, ParseError},
+    solver::{Answer, Solver},
+};
+
+/// Counts stones after blinking; the puzzle blinks 25 times for part one and 75 for part two.
+pub struct Day11 {
+    pub part_one_blinks: usize,
+    pub part_two_blinks: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            part_one_blinks: 25,
+            part_two_blinks: 75,
+        }
+    }
+}
+
+impl Solver for Day11 {
+    type Input = Vec<i64>;
+
+    const DAY: u32 = 11;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return get_inital_arrangement_from_file(raw_data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_stone_count_after_blinks(input, self.part_one_blinks).to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_stone_count_after_blinks(input, self.part_two_blinks).to_string());
+    }
+}
+
+fn get_stone_count_after_blinks(initial_arrangment: &Vec<i64>, depth: usize) -> i64 {
+    let mut hash_map: HashMap<i64, i64> = HashMap::new();
+    for &number in initial_arrangment {
+        hash_map.insert(number, 1);
+    }
+
+    for _i in 0..depth {
+        apply_rules2(&mut hash_map);
+
+        // println!("eng: {:?}", hash_map);
+    }
+    let mut sum = 0;
+    for v in hash_map.values() {
+        sum += v;
+    }
+    info!("Number of Stones: {}", sum);
+    return sum;
+}
+
+fn apply_rules2(engravings: &mut HashMap<i64, i64>) {
+    let mut temp_hash = HashMap::new();
+    //println!("eng: {:?}", engravings);
+
+    for (&key, &value) in engravings.iter() {
+        if key == 0 {
+            let mut count = 0;
+            if let Some(temp) = temp_hash.get(&1) {
+                count = *temp;
+            }
+            temp_hash.insert(1, value + count);
+        } else if key.to_string().len() % 2 == 0 {
+            let num_str = key.to_string();
+            let midpoint = num_str.char_indices().count() / 2;
+            let (first_str, second_str) = num_str.split_at_checked(midpoint).unwrap();
+            let first = first_str.parse::<i64>().unwrap();
+            let second = second_str.parse::<i64>().unwrap();
+            let mut count = 0;
+
+            if let Some(temp) = temp_hash.get(&first) {
+                count = *temp;
+            }
+            temp_hash.insert(first, value + count);
+
+            let mut count = 0;
+            if let Some(temp) = temp_hash.get(&second) {
+                count = *temp;
+            }
+            temp_hash.insert(second, value + count);
+        } else {
+            let mut count = 0;
+            if let Some(temp) = temp_hash.get(&(key * 2024)) {
+                count = *temp;
+            }
+            temp_hash.insert(key * 2024, value + count);
+        }
+    }
+    std::mem::swap(engravings, &mut temp_hash);
+}
+
+fn get_inital_arrangement_from_file(file: &str) -> Result<Vec<i64>, ParseError> {
+    let mut initial_arrangment = Vec::new();
+    for line in file.lines() {
+        let nums: Vec<i64> = line
+            .split(" ")
+            .map(|number| parse_token::<i64>(file, number))
+            .collect::<Result<_, _>>()?;
+        initial_arrangment.append(&mut nums.clone());
+    }
+    return Ok(initial_arrangment);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn stone_count_matches_sample() {
+        let stones = Day11::default().parse(TEST_CASE).unwrap();
+        assert_eq!(get_stone_count_after_blinks(&stones, 6), 22);
+        assert_eq!(get_stone_count_after_blinks(&stones, 25), 55312);
+    }
+
+    #[test]
+    fn part_one_uses_configured_blinks() {
+        let day = Day11 {
+            part_one_blinks: 1,
+            part_two_blinks: 75,
+        };
+        let stones = day.parse("0 1 10 99 999").unwrap();
+        assert_eq!(day.part_one(&stones), Some("7".to_string()));
+    }
+}
@@ -0,0 +1,124 @@
+use log::debug;
+use std::{char, collections::HashSet};
+
+use aoc_common::{
+    error::ParseError,
+    grid::{get_grid_from_raw_data, Grid},
+    solver::{Answer, Solver},
+};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+struct Coordinate {
+    value: char,
+    x: usize,
+    y: usize,
+}
+
+type Map = Grid;
+
+/// Garden regions are identified and measured, but fence pricing isn't solved yet.
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Map;
+
+    const DAY: u32 = 12;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return Ok(get_grid_from_raw_data(raw_data));
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let unique_names = get_unique_region_names(input);
+        let all_coords_by_region_name = get_all_regions_from_map(input, &unique_names);
+        for blah in all_coords_by_region_name {
+            debug!("Region:\n{}", render_region(&blah, input));
+            debug!("Area: {}", calculate_area(&blah));
+        }
+        return None;
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Option<Answer> {
+        return None;
+    }
+}
+
+fn get_all_regions_from_map(
+    map: &Map,
+    unique_region_names: &HashSet<char>,
+) -> Vec<Vec<Coordinate>> {
+    let mut vec = Vec::new();
+    for name in unique_region_names {
+        let mut coords = Vec::new();
+        for x in 0..map.len() {
+            for y in 0..map[x].len() {
+                if map[x][y] == *name {
+                    coords.push(Coordinate {
+                        value: map[x][y],
+                        x,
+                        y,
+                    });
+                }
+            }
+        }
+        vec.push(coords);
+    }
+    return vec;
+}
+fn get_unique_region_names(map: &Map) -> HashSet<char> {
+    let mut hash_set = HashSet::new();
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            hash_set.insert(map[x][y]);
+        }
+    }
+
+    return hash_set;
+}
+
+fn render_region(region: &Vec<Coordinate>, map: &Map) -> String {
+    let mut rendered = String::new();
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            if region.contains(&Coordinate {
+                value: region[0].value,
+                x,
+                y,
+            }) {
+                rendered.push(region[0].value);
+            } else {
+                rendered.push('.');
+            }
+        }
+        rendered.push('\n');
+    }
+    return rendered;
+}
+
+fn calculate_area(coordinates: &Vec<Coordinate>) -> u64 {
+    let area = coordinates.len() as u64;
+    return area;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn region_areas_match_sample() {
+        let map = Day12.parse(TEST_CASE).unwrap();
+        let names = get_unique_region_names(&map);
+        assert_eq!(names, HashSet::from(['A', 'B', 'C', 'D', 'E']));
+        let mut areas: Vec<(char, u64)> = get_all_regions_from_map(&map, &names)
+            .iter()
+            .map(|region| (region[0].value, calculate_area(region)))
+            .collect();
+        areas.sort();
+        assert_eq!(
+            areas,
+            vec![('A', 4), ('B', 4), ('C', 4), ('D', 1), ('E', 3)]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use aoc_common::{
+    blocks::get_blocks_from_raw_data,
+    error::{parse_token, ParseError},
+    solver::{Answer, Solver},
+};
+use log::{debug, info, trace};
+use regex::{Captures, Regex};
+
+/// Limits applied when pressing the claw machine buttons.
+#[derive(Debug, Clone, Copy)]
+pub struct ClawRules {
+    /// Each button may be pressed fewer than this many times; 0 means unlimited.
+    pub button_limit: u64,
+    /// Added to both prize coordinates.
+    pub unit_offset: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Offset {
+    x: i64,
+    y: i64,
+}
+#[derive(Debug, Clone, Copy)]
+pub struct ClawMachineConfig {
+    a_button: Offset,
+    b_button: Offset,
+    prize: Offset,
+}
+
+/// The puzzle caps presses at 100 in part one and moves every prize by 10000000000000 in part two.
+pub struct Day13 {
+    pub part_one: ClawRules,
+    pub part_two: ClawRules,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Day13 {
+            part_one: ClawRules {
+                button_limit: 100,
+                unit_offset: 0,
+            },
+            part_two: ClawRules {
+                button_limit: 0,
+                unit_offset: 10000000000000,
+            },
+        }
+    }
+}
+
+impl Solver for Day13 {
+    type Input = Vec<ClawMachineConfig>;
+
+    const DAY: u32 = 13;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return get_claw_machine_configs_from_file(raw_data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_total_min_tokens(input, &self.part_one).to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        return Some(get_total_min_tokens(input, &self.part_two).to_string());
+    }
+}
+
+fn get_total_min_tokens(claw_configs: &Vec<ClawMachineConfig>, rules: &ClawRules) -> i64 {
+    let mut total_min_tokens = 0;
+    for config in claw_configs {
+        let cost = get_min_token_cost_to_reach_prize(config, rules).unwrap_or(0);
+
+        debug!("Cost: {cost}");
+        total_min_tokens += cost;
+    }
+    info!("Total MIN cost: {total_min_tokens}");
+    return total_min_tokens;
+}
+
+fn get_min_token_cost_to_reach_prize(
+    claw_config: &ClawMachineConfig,
+    rules: &ClawRules,
+) -> Option<i64> {
+    let c_a = 3;
+    let c_b = 1;
+
+    let d_ax = claw_config.a_button.x;
+    let d_ay = claw_config.a_button.y;
+    let d_bx = claw_config.b_button.x;
+    let d_by = claw_config.b_button.y;
+
+    let x = claw_config.prize.x + rules.unit_offset as i64;
+    let y = claw_config.prize.y + rules.unit_offset as i64;
+
+    trace!("d_ax: {d_ax}");
+    trace!("d_ay: {d_ay}");
+    trace!("d_bx: {d_bx}");
+    trace!("d_by: {d_by}");
+    trace!("x:    {x}");
+    trace!("y:    {y}");
+
+    // Buttons moving the claw along the same line leave no single way to reach the prize.
+    let determinant = (d_by * d_ax) - (d_bx * d_ay);
+    if determinant == 0 {
+        return None;
+    }
+    let n_a = ((d_by * x) - (d_bx * y)) / determinant;
+    let n_b = ((d_ay * x) - (d_ax * y)) / -determinant;
+
+    trace!("n_a:  {n_a}");
+    trace!("n_b:  {n_b}");
+
+    if (n_a * d_ax) + (n_b * d_bx) != x && (n_a * d_ay) + (n_b * d_by) != y {
+        return None;
+    }
+    let button_limit = rules.button_limit;
+    if button_limit > 0 && (n_a >= button_limit as i64 || n_b >= button_limit as i64) {
+        return None;
+    }
+
+    let cost = (c_a * n_a) + (c_b * n_b);
+
+    return Some(cost);
+}
+
+fn get_claw_machine_configs_from_file(file: &str) -> Result<Vec<ClawMachineConfig>, ParseError> {
+    let text_blocks = get_blocks_from_raw_data(file);
+    let mut claw_configs = Vec::new();
+
+    let regex: Regex = Regex::new(r"X[+=]([0-9]*), Y[+=]([0-9]*)").unwrap();
+
+    for block in text_blocks {
+        if block.is_empty() {
+            continue;
+        }
+        let mut caps: Vec<Captures> = Vec::new();
+        for line in &block {
+            caps.append(&mut regex.captures_iter(line).collect());
+        }
+        if caps.len() != 3 {
+            return Err(ParseError::at(
+                file,
+                block[0],
+                "expected 'Button A', 'Button B' and 'Prize' lines with X and Y values",
+            ));
+        }
+        claw_configs.push(ClawMachineConfig {
+            a_button: Offset {
+                x: parse_token::<i64>(file, &caps[0][1])?,
+                y: parse_token::<i64>(file, &caps[0][2])?,
+            },
+            b_button: Offset {
+                x: parse_token::<i64>(file, &caps[1][1])?,
+                y: parse_token::<i64>(file, &caps[1][2])?,
+            },
+            prize: Offset {
+                x: parse_token::<i64>(file, &caps[2][1])?,
+                y: parse_token::<i64>(file, &caps[2][2])?,
+            },
+        });
+    }
+    //println!("ClawMachineConfigs: {:#?}", claw_configs);
+    return Ok(claw_configs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn min_token_cost_matches_sample() {
+        let configs = Day13::default().parse(TEST_CASE).unwrap();
+        let rules = Day13::default().part_one;
+        let costs: Vec<Option<i64>> = configs
+            .iter()
+            .map(|config| get_min_token_cost_to_reach_prize(config, &rules))
+            .collect();
+        assert_eq!(costs, vec![Some(280), None, Some(200), None]);
+    }
+
+    #[test]
+    fn offset_prizes_are_reachable_by_other_machines() {
+        let configs = Day13::default().parse(TEST_CASE).unwrap();
+        let rules = Day13::default().part_two;
+        let reachable: Vec<bool> = configs
+            .iter()
+            .map(|config| get_min_token_cost_to_reach_prize(config, &rules).is_some())
+            .collect();
+        assert_eq!(reachable, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn collinear_buttons_reach_nothing() {
+        let configs = Day13::default()
+            .parse("Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=4\n")
+            .unwrap();
+        let rules = Day13::default().part_one;
+        assert_eq!(get_min_token_cost_to_reach_prize(&configs[0], &rules), None);
+    }
+
+    #[test]
+    fn sample_answers() {
+        let day = Day13::default();
+        let configs = day.parse(TEST_CASE).unwrap();
+        assert_eq!(day.part_one(&configs), Some("480".to_string()));
+    }
+}
@@ -0,0 +1,363 @@
+use aoc_common::{
+    error::{parse_token, ParseError, SolveError},
+    solver::{Answer, Part, Solver},
+};
+use log::debug;
+use rustc_hash::FxHashSet;
+
+#[derive(Default, Debug, Clone)]
+pub struct CPU {
+    a: i64,
+    b: i64,
+    c: i64,
+    out: Vec<i64>,
+    pc: usize,
+    program: Vec<i64>,
+}
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = CPU;
+
+    const DAY: u32 = 17;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let mut cpu = CPU {
+            ..Default::default()
+        };
+        cpu.startup(raw_data)?;
+        return Ok(cpu);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let mut cpu = input.clone();
+        //println!("CPU: {:?}", cpu);
+        cpu.run();
+        cpu.print_output();
+        return Some(cpu.get_output());
+    }
+
+    /// `None` if no value of register A makes the program output itself.
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let mut cpu = input.clone();
+        let a = cpu.find_quine()?;
+        debug!("A: {}", a);
+        return Some(a.to_string());
+    }
+
+    fn try_solve(&self, input: &Self::Input, part: Part) -> Result<Option<Answer>, SolveError> {
+        return match self.solve(input, part) {
+            None if part == Part::Two => Err(SolveError::new(
+                "no value of register A makes the program output itself",
+            )),
+            answer => Ok(answer),
+        };
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    ADV,
+    BXL,
+    BST,
+    JNZ,
+    BXC,
+    OUT,
+    BDV,
+    CDV,
+}
+
+impl TryFrom<i64> for Instruction {
+    type Error = ();
+    fn try_from(v: i64) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Instruction::ADV as i64 => Ok(Instruction::ADV),
+            x if x == Instruction::BXL as i64 => Ok(Instruction::BXL),
+            x if x == Instruction::BST as i64 => Ok(Instruction::BST),
+            x if x == Instruction::JNZ as i64 => Ok(Instruction::JNZ),
+            x if x == Instruction::BXC as i64 => Ok(Instruction::BXC),
+            x if x == Instruction::OUT as i64 => Ok(Instruction::OUT),
+            x if x == Instruction::BDV as i64 => Ok(Instruction::BDV),
+            x if x == Instruction::CDV as i64 => Ok(Instruction::CDV),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Instruction {
+    /// Whether the operand is a combo operand, which may refer to a register, rather than a
+    /// literal.
+    fn takes_combo_operand(&self) -> bool {
+        return matches!(
+            self,
+            Instruction::ADV
+                | Instruction::BST
+                | Instruction::OUT
+                | Instruction::BDV
+                | Instruction::CDV
+        );
+    }
+}
+
+impl CPU {
+    fn startup(&mut self, raw_data: &str) -> Result<(), ParseError> {
+        let lines: Vec<&str> = raw_data.lines().collect();
+        self.a = parse_token::<i64>(raw_data, get_value(raw_data, &lines, 0, "Register A")?)?;
+        self.b = parse_token::<i64>(raw_data, get_value(raw_data, &lines, 1, "Register B")?)?;
+        self.c = parse_token::<i64>(raw_data, get_value(raw_data, &lines, 2, "Register C")?)?;
+        let listing = get_value(raw_data, &lines, 4, "Program")?;
+        if listing.trim().is_empty() {
+            return Err(ParseError::at(
+                raw_data,
+                listing,
+                "expected at least one instruction",
+            ));
+        }
+        let tokens: Vec<&str> = listing.split(',').collect();
+        let mut program = Vec::new();
+        for i in &tokens {
+            let value = parse_token::<i64>(raw_data, i)?;
+            if Instruction::try_from(value).is_err() {
+                return Err(ParseError::at(
+                    raw_data,
+                    i,
+                    "expected a 3-bit number from 0 to 7",
+                ));
+            }
+            program.push(value);
+        }
+        for pc in (0..program.len() - 1).step_by(2) {
+            let instruction = Instruction::try_from(program[pc]).unwrap();
+            if instruction.takes_combo_operand() && program[pc + 1] == 7 {
+                return Err(ParseError::at(
+                    raw_data,
+                    tokens[pc + 1],
+                    format!(
+                        "combo operand 7 is reserved, so {:?} can't take it",
+                        instruction
+                    ),
+                ));
+            }
+        }
+        self.program = program;
+        return Ok(());
+    }
+
+    fn run(&mut self) {
+        while self.pc + 1 < self.program.len() {
+            //println!("PC: {}", self.pc);
+            let current_instruction = Instruction::try_from(self.program[self.pc]).unwrap();
+            match current_instruction {
+                Instruction::ADV => self.adv(self.program[self.pc + 1]),
+                Instruction::BXL => self.bxl(self.program[self.pc + 1]),
+                Instruction::BST => self.bst(self.program[self.pc + 1]),
+                Instruction::JNZ => self.jnz(self.program[self.pc + 1]),
+                Instruction::BXC => self.bxc(self.program[self.pc + 1]),
+                Instruction::OUT => self.out(self.program[self.pc + 1]),
+                Instruction::BDV => self.bdv(self.program[self.pc + 1]),
+                Instruction::CDV => self.cdv(self.program[self.pc + 1]),
+            }
+        }
+    }
+
+    /// The lowest value of register A that makes the program output itself, if any does.
+    fn find_quine(&mut self) -> Option<usize> {
+        let mut quines = FxHashSet::default();
+        quines.insert(0);
+        for instruction in self.program.clone().iter().rev() {
+            let mut new_quines = FxHashSet::default();
+            for curr in quines {
+                for i in 0..8 {
+                    let new = (curr << 3) + i;
+
+                    let out = self.run_single_out(new);
+                    //println!("Run({}): {:?}", new, out);
+                    if out == Some(*instruction) {
+                        new_quines.insert(new);
+                    }
+                }
+            }
+            quines = new_quines;
+        }
+        debug!("Quines: {:?}", quines);
+        return quines.iter().min().map(|a| *a as usize);
+    }
+
+    /// The first value the program outputs, if it outputs any.
+    fn run_single_out(&mut self, initial_a: i64) -> Option<i64> {
+        self.pc = 0;
+        self.b = 0;
+        self.c = 0;
+        self.out.clear();
+
+        self.a = initial_a;
+        self.run();
+        //self.print_output();
+        return self.out.first().copied();
+    }
+
+    fn get_output(&self) -> String {
+        return self
+            .out
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+    }
+
+    fn print_output(&self) {
+        debug!("Output: [{}]", self.get_output());
+    }
+
+    fn adv(&mut self, operand: i64) {
+        self.a /= 2_i64.pow(self.get_combo_operand(operand).unwrap() as u32);
+        self.pc += 2;
+    }
+
+    fn bxl(&mut self, operand: i64) {
+        self.b ^= operand;
+        self.pc += 2;
+    }
+
+    fn bst(&mut self, operand: i64) {
+        self.b = self.get_combo_operand(operand).unwrap() % 8;
+        self.pc += 2;
+    }
+
+    fn jnz(&mut self, operand: i64) {
+        if self.a != 0 {
+            self.pc = operand as usize;
+        } else {
+            self.pc += 2;
+        }
+    }
+
+    fn bxc(&mut self, _operand: i64) {
+        self.b ^= self.c;
+        self.pc += 2;
+    }
+
+    fn out(&mut self, operand: i64) {
+        self.out.push(self.get_combo_operand(operand).unwrap() % 8);
+        self.pc += 2;
+    }
+
+    fn bdv(&mut self, operand: i64) {
+        self.b = self.a / (2_i64.pow(self.get_combo_operand(operand).unwrap() as u32));
+        self.pc += 2;
+    }
+
+    fn cdv(&mut self, operand: i64) {
+        self.c = self.a / (2_i64.pow(self.get_combo_operand(operand).unwrap() as u32));
+        self.pc += 2;
+    }
+
+    fn get_combo_operand(&self, operand: i64) -> Result<i64, &str> {
+        match operand {
+            0..=3 => return Ok(operand),
+            4 => return Ok(self.a),
+            5 => return Ok(self.b),
+            6 => return Ok(self.c),
+            _ => return Err("Match not defined"),
+        }
+    }
+}
+
+/// Returns the text after `<label>: ` on the given line of the program listing.
+fn get_value<'a>(
+    raw_data: &str,
+    lines: &Vec<&'a str>,
+    index: usize,
+    label: &str,
+) -> Result<&'a str, ParseError> {
+    let expected = format!("expected a '{}: ' line", label);
+    let line = match lines.get(index) {
+        Some(line) => *line,
+        None => return Err(ParseError::at_end(raw_data, expected)),
+    };
+    return match line.split_once(": ") {
+        Some((name, value)) if name == label => Ok(value),
+        _ => Err(ParseError::at(raw_data, line, expected)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+    const TEST_CASE_QUINE: &str = include_str!("../source_data/test_case_quine.txt");
+
+    fn get_cpu(a: i64, b: i64, c: i64, program: Vec<i64>) -> CPU {
+        return CPU {
+            a,
+            b,
+            c,
+            program,
+            ..Default::default()
+        };
+    }
+
+    #[test]
+    fn run_matches_instruction_examples() {
+        let mut cpu = get_cpu(0, 0, 9, vec![2, 6]);
+        cpu.run();
+        assert_eq!(cpu.b, 1);
+
+        let mut cpu = get_cpu(10, 0, 0, vec![5, 0, 5, 1, 5, 4]);
+        cpu.run();
+        assert_eq!(cpu.get_output(), "0,1,2");
+
+        let mut cpu = get_cpu(2024, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        cpu.run();
+        assert_eq!(cpu.get_output(), "4,2,5,6,7,7,7,7,3,1,0");
+        assert_eq!(cpu.a, 0);
+
+        let mut cpu = get_cpu(0, 29, 0, vec![1, 7]);
+        cpu.run();
+        assert_eq!(cpu.b, 26);
+
+        let mut cpu = get_cpu(0, 2024, 43690, vec![4, 0]);
+        cpu.run();
+        assert_eq!(cpu.b, 44354);
+    }
+
+    #[test]
+    fn sample_answers() {
+        let cpu = Day17.parse(TEST_CASE).unwrap();
+        assert_eq!(
+            Day17.part_one(&cpu),
+            Some("4,6,3,5,6,3,5,2,1,0".to_string())
+        );
+
+        let cpu = Day17.parse(TEST_CASE_QUINE).unwrap();
+        assert_eq!(Day17.part_two(&cpu), Some("117440".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_opcodes() {
+        assert!(Day17
+            .parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 9,0")
+            .is_err());
+    }
+
+    #[test]
+    fn parse_rejects_reserved_combo_operands_and_empty_programs() {
+        let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+        let err = Day17
+            .parse(&format!("{}Program: 1,7,0,7", registers))
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (5, 16, "7"));
+        assert!(Day17.parse(&format!("{}Program: 1,7", registers)).is_ok());
+        assert!(Day17.parse(&format!("{}Program: ", registers)).is_err());
+    }
+
+    #[test]
+    fn part_two_fails_without_a_quine() {
+        let cpu = Day17
+            .parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3")
+            .unwrap();
+        assert_eq!(Day17.part_two(&cpu), None);
+        assert!(Day17.try_solve(&cpu, Part::Two).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use crate::policy::{Direction, SafetyPolicy, ShortReports};
+
+const DIRECTIONS: [Direction; 3] = [
+    Direction::Either,
+    Direction::Increasing,
+    Direction::Decreasing,
+];
+
+fn get_direction_index(direction: Direction) -> usize {
+    return match direction {
+        Direction::Either => 0,
+        Direction::Increasing => 1,
+        Direction::Decreasing => 2,
+    };
+}
+
+/// The fewest levels to remove, at most `tolerance` of them, for the rest of the report to be safe.
+///
+/// Returns the indices of the removed levels in ascending order, which is empty for a report that
+/// is already safe, or `None` if removing `tolerance` levels isn't enough. Unless the policy says
+/// short reports are safe, at least 2 levels must be kept.
+///
+/// Finds the longest safe run of kept levels with a single pass: a level can only follow one of
+/// the `tolerance + 1` levels before it, as skipping more would remove too many, so checking a
+/// report of `n` levels takes O(n * tolerance) steps.
+pub fn get_levels_to_remove(
+    report: &Vec<i32>,
+    policy: &SafetyPolicy,
+    tolerance: usize,
+) -> Option<Vec<usize>> {
+    let n = report.len();
+    let short_reports_are_safe = policy.short_reports == ShortReports::Safe;
+    if n < 2 {
+        return if short_reports_are_safe {
+            Some(Vec::new())
+        } else {
+            None
+        };
+    }
+
+    // removals[i][d]: the fewest levels removed before index i, when index i is kept and the kept
+    // levels so far are safe and moving in direction d.
+    let mut removals = vec![[None; 3]; n];
+    let mut previous: Vec<[Option<(usize, usize)>; 3]> = vec![[None; 3]; n];
+    for i in 0..n {
+        if i <= tolerance {
+            removals[i][get_direction_index(policy.direction)] = Some(i);
+        }
+        for j in i.saturating_sub(tolerance + 1)..i {
+            for d in 0..DIRECTIONS.len() {
+                let Some(removed) = removals[j][d] else {
+                    continue;
+                };
+                let Ok(direction) = policy.check_step(report[j], report[i], DIRECTIONS[d]) else {
+                    continue;
+                };
+                let removed = removed + (i - j - 1);
+                let next = get_direction_index(direction);
+                if removed <= tolerance && removals[i][next].is_none_or(|best| removed < best) {
+                    removals[i][next] = Some(removed);
+                    previous[i][next] = Some((j, d));
+                }
+            }
+        }
+    }
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    for i in n.saturating_sub(tolerance + 1)..n {
+        for d in 0..DIRECTIONS.len() {
+            if let Some(removed) = removals[i][d] {
+                let removed = removed + (n - 1 - i);
+                if removed <= tolerance && best.is_none_or(|(fewest, _, _)| removed < fewest) {
+                    best = Some((removed, i, d));
+                }
+            }
+        }
+    }
+
+    let (removed, last, direction) = best?;
+    if n - removed < 2 && !short_reports_are_safe {
+        return None;
+    }
+    let mut kept = vec![false; n];
+    let mut current = Some((last, direction));
+    while let Some((i, d)) = current {
+        kept[i] = true;
+        current = previous[i][d];
+    }
+    return Some((0..n).filter(|i| !kept[*i]).collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_safe_report;
+
+    /// Tries every way of removing up to `tolerance` levels.
+    fn get_fewest_removals_by_brute_force(
+        report: &Vec<i32>,
+        policy: &SafetyPolicy,
+        tolerance: usize,
+    ) -> Option<usize> {
+        return (0..=tolerance.min(report.len()))
+            .find(|removals| can_remove(report, policy, *removals, 0));
+    }
+
+    fn can_remove(report: &Vec<i32>, policy: &SafetyPolicy, removals: usize, from: usize) -> bool {
+        if removals == 0 {
+            return is_safe_report(report, policy);
+        }
+        for i in from..report.len() {
+            let mut short_report = report.clone();
+            short_report.remove(i);
+            if can_remove(&short_report, policy, removals - 1, i) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    #[test]
+    fn removes_nothing_from_safe_reports() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(
+            get_levels_to_remove(&vec![7, 6, 4, 2, 1], &policy, 1),
+            Some(vec![])
+        );
+        assert_eq!(get_levels_to_remove(&vec![], &policy, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn matches_sample() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(get_levels_to_remove(&vec![1, 2, 7, 8, 9], &policy, 1), None);
+        assert_eq!(get_levels_to_remove(&vec![9, 7, 6, 2, 1], &policy, 1), None);
+        assert_eq!(
+            get_levels_to_remove(&vec![1, 3, 2, 4, 5], &policy, 1),
+            Some(vec![2])
+        );
+        assert_eq!(
+            get_levels_to_remove(&vec![8, 6, 4, 4, 1], &policy, 1),
+            Some(vec![3])
+        );
+    }
+
+    #[test]
+    fn removes_up_to_tolerance_levels() {
+        let policy = SafetyPolicy::default();
+        let report = vec![1, 9, 2, 3, 9, 4];
+        assert_eq!(get_levels_to_remove(&report, &policy, 1), None);
+        assert_eq!(get_levels_to_remove(&report, &policy, 2), Some(vec![1, 4]));
+        assert_eq!(
+            get_levels_to_remove(&vec![5, 1, 2, 3], &policy, 1),
+            Some(vec![0])
+        );
+        assert_eq!(
+            get_levels_to_remove(&vec![1, 2, 3, 9], &policy, 1),
+            Some(vec![3])
+        );
+    }
+
+    #[test]
+    fn keeps_two_levels_unless_short_reports_are_safe() {
+        let mut policy = SafetyPolicy::default();
+        assert_eq!(get_levels_to_remove(&vec![1, 9], &policy, 1), Some(vec![1]));
+        assert_eq!(get_levels_to_remove(&vec![5], &policy, 0), Some(vec![]));
+        policy.short_reports = ShortReports::Unsafe;
+        assert_eq!(get_levels_to_remove(&vec![1, 9], &policy, 1), None);
+        assert_eq!(get_levels_to_remove(&vec![5], &policy, 0), None);
+        assert_eq!(
+            get_levels_to_remove(&vec![1, 9, 2], &policy, 1),
+            Some(vec![1])
+        );
+    }
+
+    #[test]
+    fn removals_leave_a_safe_report() {
+        let policy = SafetyPolicy::default();
+        let reports = vec![
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![48, 46, 47, 49, 51, 54, 56],
+            vec![1, 1, 2, 3, 4, 5],
+            vec![29, 28, 27, 25, 26, 25, 22, 20],
+            vec![7, 10, 8, 10, 11],
+            vec![90, 89, 86, 84, 83, 79],
+            vec![1, 5, 6, 7, 2, 8, 12, 9],
+            vec![3, 3, 3, 3],
+        ];
+        for tolerance in 0..4 {
+            for report in &reports {
+                let removed = get_levels_to_remove(report, &policy, tolerance);
+                assert_eq!(
+                    removed.as_ref().map(|removed| removed.len()),
+                    get_fewest_removals_by_brute_force(report, &policy, tolerance),
+                    "{:?} with tolerance {}",
+                    report,
+                    tolerance
+                );
+                if let Some(removed) = removed {
+                    let kept: Vec<i32> = (0..report.len())
+                        .filter(|i| !removed.contains(i))
+                        .map(|i| report[i])
+                        .collect();
+                    assert!(is_safe_report(&kept, &policy));
+                }
+            }
+        }
+    }
+}
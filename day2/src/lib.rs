@@ -0,0 +1,389 @@
+use std::fmt;
+
+use aoc_common::{
+    error::{parse_token, ParseError},
+    solver::{Answer, Solver},
+};
+use dampener::get_levels_to_remove;
+use log::{debug, info, trace};
+use policy::{SafetyPolicy, ShortReports, StepError};
+use serde_json::json;
+
+pub mod dampener;
+pub mod policy;
+
+/// A report's levels, along with the line of the input it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub line: usize,
+    pub levels: Vec<i32>,
+}
+
+/// Part two's Problem Dampener removes up to `tolerance` levels, one in the puzzle, to make a
+/// report safe.
+pub struct Day2 {
+    pub policy: SafetyPolicy,
+    pub tolerance: usize,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Day2 {
+            policy: SafetyPolicy::default(),
+            tolerance: 1,
+        }
+    }
+}
+
+impl Solver for Day2 {
+    type Input = Vec<Report>;
+
+    const DAY: u32 = 2;
+
+    /// Blank lines and comments starting with `#` are skipped.
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let mut data = Vec::new();
+        for (i, line) in raw_data.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let levels: Vec<i32> = line
+                .split_whitespace()
+                .map(|num| parse_token::<i32>(raw_data, num))
+                .collect::<Result<_, _>>()?;
+            if levels.len() < 2 && self.policy.short_reports == ShortReports::Error {
+                return Err(ParseError::at(
+                    raw_data,
+                    trimmed,
+                    "expected a report with at least 2 levels",
+                ));
+            }
+            data.push(Report {
+                line: i + 1,
+                levels,
+            });
+        }
+        return Ok(data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let (safe_report_count, _) = get_safe_report_count(input, &self.policy);
+        info!("Total Safe Reports: {}/{}", safe_report_count, input.len());
+        return Some(safe_report_count.to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let (mut safe_report_count, failed_report_indexes) =
+            get_safe_report_count(input, &self.policy);
+
+        debug!("Failed Report Numbers: {:?}", failed_report_indexes);
+        debug!("INITIALIZING PROBLEM DAMPENER (tm)...");
+        for report_index in failed_report_indexes {
+            let report = &input[report_index];
+            let removed = get_levels_to_remove(&report.levels, &self.policy, self.tolerance);
+            if let Some(removed) = removed {
+                debug!(
+                    "Report on line {} is safe after removing levels at {:?}",
+                    report.line, removed
+                );
+                safe_report_count += 1;
+            }
+        }
+        info!(
+            "Total Safe Reports with Problem Dampener(tm): {}/{}",
+            safe_report_count,
+            input.len()
+        );
+        return Some(safe_report_count.to_string());
+    }
+}
+
+fn get_safe_report_count(data: &Vec<Report>, policy: &SafetyPolicy) -> (usize, Vec<usize>) {
+    let mut safe_report_count = 0;
+    let mut failed_report_indexes = Vec::new();
+    for i in 0..data.len() {
+        let is_safe = is_safe_report(&data[i].levels, policy);
+        if is_safe {
+            safe_report_count += 1;
+        } else {
+            failed_report_indexes.push(i);
+        }
+    }
+    return (safe_report_count, failed_report_indexes);
+}
+
+/// Why a report is or isn't safe; `index` is the level at the end of the first unsafe step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// The report has fewer than 2 levels and the policy treats such reports as unsafe.
+    TooShort {
+        levels: usize,
+    },
+    DirectionChange {
+        index: usize,
+    },
+    StepTooSmall {
+        index: usize,
+        step: u32,
+    },
+    StepTooLarge {
+        index: usize,
+        step: u32,
+    },
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::TooShort { levels } => write!(f, "too few levels: {}", levels),
+            Verdict::DirectionChange { index } => {
+                write!(f, "direction change at index {}", index)
+            }
+            Verdict::StepTooSmall { index, step } => {
+                write!(f, "step too small at index {}: {}", index, step)
+            }
+            Verdict::StepTooLarge { index, step } => {
+                write!(f, "step too large at index {}: {}", index, step)
+            }
+        }
+    }
+}
+
+impl Verdict {
+    /// A short, stable name for the verdict, for machine readable output.
+    pub fn get_kind(&self) -> &'static str {
+        return match self {
+            Verdict::Safe => "safe",
+            Verdict::TooShort { .. } => "too-short",
+            Verdict::DirectionChange { .. } => "direction-change",
+            Verdict::StepTooSmall { .. } => "step-too-small",
+            Verdict::StepTooLarge { .. } => "step-too-large",
+        };
+    }
+}
+
+/// Checks every step between adjacent levels against the policy, stopping at the first unsafe one.
+///
+/// Reports with fewer than 2 levels have no steps; they are safe only if the policy says so.
+pub fn get_report_verdict(report: &Vec<i32>, policy: &SafetyPolicy) -> Verdict {
+    trace!("{:?}", report);
+    if report.len() < 2 && policy.short_reports != ShortReports::Safe {
+        return Verdict::TooShort {
+            levels: report.len(),
+        };
+    }
+    let mut direction = policy.direction;
+    for i in 1..report.len() {
+        match policy.check_step(report[i - 1], report[i], direction) {
+            Ok(next_direction) => direction = next_direction,
+            Err(StepError::DirectionChange) => return Verdict::DirectionChange { index: i },
+            Err(StepError::TooSmall(step)) => return Verdict::StepTooSmall { index: i, step },
+            Err(StepError::TooLarge(step)) => return Verdict::StepTooLarge { index: i, step },
+        }
+    }
+    return Verdict::Safe;
+}
+
+pub fn is_safe_report(report: &Vec<i32>, policy: &SafetyPolicy) -> bool {
+    return get_report_verdict(report, policy) == Verdict::Safe;
+}
+
+/// Why an unsafe report failed, and which levels the dampener would remove to make it safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The line of the input the report came from.
+    pub line: usize,
+    pub verdict: Verdict,
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Explanation {
+    pub fn to_text(&self) -> String {
+        let dampener = match &self.removed {
+            Some(removed) => format!("safe after removing levels at {:?}", removed),
+            None => "still unsafe".to_string(),
+        };
+        return format!(
+            "Line {}: {}; with the dampener: {}",
+            self.line, self.verdict, dampener
+        );
+    }
+
+    pub fn to_json(&self, input: &str) -> String {
+        let (index, step) = match self.verdict {
+            Verdict::Safe | Verdict::TooShort { .. } => (None, None),
+            Verdict::DirectionChange { index } => (Some(index), None),
+            Verdict::StepTooSmall { index, step } | Verdict::StepTooLarge { index, step } => {
+                (Some(index), Some(step))
+            }
+        };
+        return json!({
+            "input": input,
+            "line": self.line,
+            "verdict": self.verdict.get_kind(),
+            "index": index,
+            "step": step,
+            "removed": self.removed,
+        })
+        .to_string();
+    }
+}
+
+/// Explains every unsafe report, in input order.
+pub fn get_explanations(
+    reports: &Vec<Report>,
+    policy: &SafetyPolicy,
+    tolerance: usize,
+) -> Vec<Explanation> {
+    let mut explanations = Vec::new();
+    for report in reports {
+        let verdict = get_report_verdict(&report.levels, policy);
+        if verdict == Verdict::Safe {
+            continue;
+        }
+        explanations.push(Explanation {
+            line: report.line,
+            verdict,
+            removed: get_levels_to_remove(&report.levels, policy, tolerance),
+        });
+    }
+    return explanations;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use policy::Direction;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn is_safe_report_matches_sample() {
+        let policy = SafetyPolicy::default();
+        assert!(is_safe_report(&vec![7, 6, 4, 2, 1], &policy));
+        assert!(!is_safe_report(&vec![1, 2, 7, 8, 9], &policy));
+        assert!(!is_safe_report(&vec![9, 7, 6, 2, 1], &policy));
+        assert!(!is_safe_report(&vec![1, 3, 2, 4, 5], &policy));
+        assert!(!is_safe_report(&vec![8, 6, 4, 4, 1], &policy));
+        assert!(is_safe_report(&vec![1, 3, 6, 7, 9], &policy));
+    }
+
+    #[test]
+    fn verdicts_match_sample() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(
+            get_report_verdict(&vec![7, 6, 4, 2, 1], &policy),
+            Verdict::Safe
+        );
+        assert_eq!(
+            get_report_verdict(&vec![1, 2, 7, 8, 9], &policy),
+            Verdict::StepTooLarge { index: 2, step: 5 }
+        );
+        assert_eq!(
+            get_report_verdict(&vec![1, 3, 2, 4, 5], &policy),
+            Verdict::DirectionChange { index: 2 }
+        );
+        assert_eq!(
+            get_report_verdict(&vec![8, 6, 4, 4, 1], &policy),
+            Verdict::StepTooSmall { index: 3, step: 0 }
+        );
+    }
+
+    #[test]
+    fn explains_only_unsafe_reports() {
+        let day2 = Day2::default();
+        let reports = day2.parse(TEST_CASE).unwrap();
+        let explanations = get_explanations(&reports, &day2.policy, day2.tolerance);
+        let explained: Vec<usize> = explanations.iter().map(|e| e.line).collect();
+        assert_eq!(explained, vec![2, 3, 4, 5]);
+        assert_eq!(
+            explanations[0].to_text(),
+            "Line 2: step too large at index 2: 5; with the dampener: still unsafe"
+        );
+        assert_eq!(
+            explanations[2].to_text(),
+            "Line 4: direction change at index 2; with the dampener: safe after removing levels at [2]"
+        );
+        assert_eq!(
+            explanations[3].to_json("day2.txt"),
+            r#"{"index":3,"input":"day2.txt","line":5,"removed":[3],"step":0,"verdict":"step-too-small"}"#
+        );
+    }
+
+    #[test]
+    fn parse_skips_blank_and_comment_lines() {
+        let reports = Day2::default()
+            .parse("# sensor 1\n7 6 4 2 1\n\n   \n  # sensor 2\n1 3 6 7 9\n")
+            .unwrap();
+        assert_eq!(
+            reports,
+            vec![
+                Report {
+                    line: 2,
+                    levels: vec![7, 6, 4, 2, 1]
+                },
+                Report {
+                    line: 6,
+                    levels: vec![1, 3, 6, 7, 9]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn short_reports_follow_policy() {
+        let raw_data = "7 6 4 2 1\n5\n";
+        let mut day2 = Day2::default();
+        let reports = day2.parse(raw_data).unwrap();
+        assert_eq!(day2.part_one(&reports), Some("2".to_string()));
+        assert_eq!(get_report_verdict(&vec![], &day2.policy), Verdict::Safe);
+
+        day2.policy.short_reports = ShortReports::Unsafe;
+        let reports = day2.parse(raw_data).unwrap();
+        assert_eq!(day2.part_one(&reports), Some("1".to_string()));
+        assert_eq!(day2.part_two(&reports), Some("1".to_string()));
+        assert_eq!(
+            get_report_verdict(&vec![5], &day2.policy),
+            Verdict::TooShort { levels: 1 }
+        );
+
+        day2.policy.short_reports = ShortReports::Error;
+        let err = day2.parse(raw_data).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn is_safe_report_follows_policy() {
+        let policy = SafetyPolicy {
+            min_step: 1,
+            max_step: 5,
+            direction: Direction::Increasing,
+            allow_plateaus: true,
+            short_reports: ShortReports::Safe,
+        };
+        assert!(is_safe_report(&vec![1, 2, 7, 7, 9], &policy));
+        assert!(!is_safe_report(&vec![7, 6, 4, 2, 1], &policy));
+        assert!(!is_safe_report(&vec![1, 3, 2, 4, 5], &policy));
+        assert!(!is_safe_report(&vec![1, 7, 8], &policy));
+    }
+
+    #[test]
+    fn safe_report_count_lists_failed_reports() {
+        let reports = Day2::default().parse(TEST_CASE).unwrap();
+        assert_eq!(
+            get_safe_report_count(&reports, &SafetyPolicy::default()),
+            (2, vec![1, 2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn sample_answers() {
+        let day2 = Day2::default();
+        let reports = day2.parse(TEST_CASE).unwrap();
+        assert_eq!(day2.part_one(&reports), Some("2".to_string()));
+        assert_eq!(day2.part_two(&reports), Some("4".to_string()));
+    }
+}
@@ -1,88 +1,114 @@
-use std::{fs, env};
+use std::{path::PathBuf, process};
 
-const MINIMUM_VARIATION: i32 = 1;
-const MAXIMUM_VARIATION: i32 = 3;
+use aoc_common::{
+    cli::{InputArgs, RunArgs},
+    file::PuzzleInputs,
+    report::OutputFormat,
+    solver::{self, Solver},
+};
+use clap::Parser;
+use day2::{
+    get_explanations,
+    policy::{Direction, SafetyPolicy, ShortReports},
+    Day2,
+};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let file_path = &args[1];
-    println!("Using file: {}", file_path);
-    let raw_data = Box::new(load_input(&file_path));
-    let mut data = Vec::new();
-    let mut failed_report_indexes = Vec::new();
-    // parse input file into 2D vec
-    for line in raw_data.lines() {
-        let temp_line: Vec<i32> = line
-            .split(' ')
-            .filter(|str| !str.is_empty())
-            .map(|num| num.trim().parse::<i32>().unwrap())
-            .collect();
-        data.push(temp_line);
-    }
-
-    let mut safe_report_count = 0;
-    // Part 1
-    for i in 0..data.len() {
-        println!("{:?}", &data[i]);
-        let is_safe = is_safe_report(&data[i]);
-        if is_safe {
-            safe_report_count += 1;
-        } else {
-            failed_report_indexes.push(i);
-        }
-    }
-
-    println!("Total Safe Reports: {}/{}", safe_report_count, &data.len());
-    // Part 2
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    run: RunArgs,
+    /// Read the safety policy from this TOML file; the flags below override it.
+    #[arg(long, value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// The smallest allowed difference between adjacent levels.
+    #[arg(long, value_name = "STEP")]
+    min_step: Option<i32>,
+    /// The largest allowed difference between adjacent levels.
+    #[arg(long, value_name = "STEP")]
+    max_step: Option<i32>,
+    /// Which way the levels of a safe report may move.
+    #[arg(long, value_enum, value_name = "DIRECTION")]
+    direction: Option<Direction>,
+    /// Allow adjacent levels to be equal.
+    #[arg(long, overrides_with = "no_allow_plateaus")]
+    allow_plateaus: bool,
+    /// Don't allow adjacent levels to be equal, even if the policy file does.
+    #[arg(long, overrides_with = "allow_plateaus")]
+    no_allow_plateaus: bool,
+    /// What to make of reports with fewer than 2 levels.
+    #[arg(long, value_enum, value_name = "HANDLING")]
+    short_reports: Option<ShortReports>,
+    /// How many levels the Problem Dampener may remove from a report in part two.
+    #[arg(short, long, value_name = "LEVELS", default_value_t = 1)]
+    tolerance: usize,
+    /// List every unsafe report with the reason it failed instead of printing the answers.
+    #[arg(long, conflicts_with_all = ["part", "bench", "check", "record"])]
+    explain: bool,
+}
 
-    println!("\nFailed Report Numbers: {:?}\n", failed_report_indexes);
-    println!("INITIALIZING PROBLEM DAMPENER (tm)...\n");
-    for report_index in failed_report_indexes {
-        for i in 0..data[report_index].len() {
-            let mut short_report = data[report_index].clone();
-            short_report.remove(i);
-            let is_safe = is_safe_report(&short_report);
-            if is_safe {
-                safe_report_count += 1;
-                break;
+impl Cli {
+    fn get_policy(&self) -> Result<SafetyPolicy, String> {
+        let mut policy = match &self.policy {
+            Some(path) => {
+                SafetyPolicy::load(path).map_err(|err| format!("'{}': {}", path.display(), err))?
             }
+            None => SafetyPolicy::default(),
+        };
+        if let Some(min_step) = self.min_step {
+            policy.min_step = min_step;
+        }
+        if let Some(max_step) = self.max_step {
+            policy.max_step = max_step;
+        }
+        if let Some(direction) = self.direction {
+            policy.direction = direction;
         }
+        if let Some(short_reports) = self.short_reports {
+            policy.short_reports = short_reports;
+        }
+        if self.allow_plateaus {
+            policy.allow_plateaus = true;
+        }
+        if self.no_allow_plateaus {
+            policy.allow_plateaus = false;
+        }
+        policy.validate()?;
+        return Ok(policy);
     }
-    println!(
-        "Total Safe Reports with Problem Dampener(tm): {}/{}",
-        safe_report_count,
-        &data.len()
-    );
 }
 
-fn load_input(file_path: &str) -> String {
-    return fs::read_to_string(file_path).expect("Unable to read file");
+fn main() {
+    let cli = Cli::parse();
+    let policy = match cli.get_policy() {
+        Ok(policy) => policy,
+        Err(err) => {
+            eprintln!("Invalid safety policy {}", err);
+            process::exit(1);
+        }
+    };
+    let puzzle_inputs = cli.input.load();
+    let day2 = Day2 {
+        policy,
+        tolerance: cli.tolerance,
+    };
+    if cli.explain {
+        print_explanations(&day2, &puzzle_inputs, &cli.run);
+        return;
+    }
+    solver::run(&day2, &puzzle_inputs, &cli.run);
 }
 
-fn is_safe_report(report: &Vec<i32>) -> bool {
-    println!("{:?}", report);
-    let starts_increasing = report[0] < report[1];
-    for i in 0..report.len() - 1 {
-        if (starts_increasing && report[i] > report[i + 1])
-            || (!starts_increasing && report[i + 1] > report[i])
-        {
-            println!("We changed directions, unsafe report!");
-            return false;
-        }
-        let difference = if starts_increasing {
-            report[i + 1] - report[i]
-        } else {
-            report[i] - report[i + 1]
-        };
-        if difference < MINIMUM_VARIATION || difference > MAXIMUM_VARIATION {
-            println!(
-                    "The difference between index {} and index {}: {} is outside the allowed range, unsafe report",
-                    i,
-                    i + 1,
-                    difference
-                );
-            return false;
+fn print_explanations(day2: &Day2, puzzle_inputs: &PuzzleInputs, args: &RunArgs) {
+    let parse = |data: &str| day2.parse(data);
+    solver::run_reports(puzzle_inputs, args, parse, |puzzle_input, reports| {
+        for explanation in get_explanations(&reports, &day2.policy, day2.tolerance) {
+            match args.format {
+                OutputFormat::Text => println!("{}", explanation.to_text()),
+                OutputFormat::Json => println!("{}", explanation.to_json(&puzzle_input.name)),
+            }
         }
-    }
-    return true;
+    });
 }
@@ -0,0 +1,214 @@
+use std::{fmt, fs, path::Path};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// Which way the levels of a safe report may move.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as every step keeps to the direction of the first.
+    #[default]
+    Either,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Increasing => write!(f, "increasing"),
+            Direction::Decreasing => write!(f, "decreasing"),
+            Direction::Either => write!(f, "either"),
+        }
+    }
+}
+
+/// What to make of a report with fewer than 2 levels, which has no steps to check.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ShortReports {
+    #[default]
+    Safe,
+    Unsafe,
+    /// Reject the input when parsing it.
+    Error,
+}
+
+/// Why a step between two adjacent levels breaks a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepError {
+    /// The step goes against the direction the report is moving in, or is required to move in.
+    DirectionChange,
+    /// The absolute difference between the levels is below the minimum step.
+    TooSmall(u32),
+    /// The absolute difference between the levels is above the maximum step.
+    TooLarge(u32),
+}
+
+/// The rules a report's levels must follow to be safe; the default is the puzzle's.
+///
+/// Policies can be read from a TOML file, where any field left out keeps its default:
+///
+/// ```toml
+/// min_step = 1
+/// max_step = 5
+/// direction = "increasing"
+/// allow_plateaus = true
+/// short_reports = "unsafe"
+/// ```
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// The smallest allowed difference between adjacent levels.
+    pub min_step: i32,
+    /// The largest allowed difference between adjacent levels.
+    pub max_step: i32,
+    pub direction: Direction,
+    /// Whether adjacent levels may be equal, whatever `min_step` is.
+    pub allow_plateaus: bool,
+    pub short_reports: ShortReports,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            allow_plateaus: false,
+            short_reports: ShortReports::Safe,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn from_toml(data: &str) -> Result<SafetyPolicy, String> {
+        let policy: SafetyPolicy = toml::from_str(data).map_err(|err| err.to_string())?;
+        policy.validate()?;
+        return Ok(policy);
+    }
+
+    pub fn load(path: &Path) -> Result<SafetyPolicy, String> {
+        let data = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        return SafetyPolicy::from_toml(&data);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_step < 0 {
+            return Err(format!(
+                "min_step must not be negative, got {}",
+                self.min_step
+            ));
+        }
+        if self.min_step > self.max_step {
+            return Err(format!(
+                "min_step ({}) must not be larger than max_step ({})",
+                self.min_step, self.max_step
+            ));
+        }
+        return Ok(());
+    }
+
+    /// Whether `next` may follow `previous`, given the direction set by the report so far.
+    ///
+    /// Returns the direction the report is moving in after this step, or why the step is unsafe.
+    /// A plateau keeps whatever direction was already set.
+    pub fn check_step(
+        &self,
+        previous: i32,
+        next: i32,
+        direction: Direction,
+    ) -> Result<Direction, StepError> {
+        // The difference between two i32 levels may not fit in an i32, but always fits in a u32.
+        let step = previous.abs_diff(next);
+        if step == 0 {
+            return if self.allow_plateaus || self.min_step == 0 {
+                Ok(direction)
+            } else {
+                Err(StepError::TooSmall(0))
+            };
+        }
+        let step_direction = if next > previous {
+            Direction::Increasing
+        } else {
+            Direction::Decreasing
+        };
+        if direction != Direction::Either && direction != step_direction {
+            return Err(StepError::DirectionChange);
+        }
+        // validate() keeps min_step, and so max_step, from being negative.
+        if step < self.min_step as u32 {
+            return Err(StepError::TooSmall(step));
+        }
+        if step > self.max_step as u32 {
+            return Err(StepError::TooLarge(step));
+        }
+        return Ok(step_direction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_the_puzzle_policy() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(
+            policy.check_step(1, 4, Direction::Either),
+            Ok(Direction::Increasing)
+        );
+        assert_eq!(
+            policy.check_step(1, 5, Direction::Either),
+            Err(StepError::TooLarge(4))
+        );
+        assert_eq!(
+            policy.check_step(4, 4, Direction::Either),
+            Err(StepError::TooSmall(0))
+        );
+        assert_eq!(
+            policy.check_step(4, 3, Direction::Increasing),
+            Err(StepError::DirectionChange)
+        );
+        assert_eq!(
+            policy.check_step(i32::MAX, i32::MIN, Direction::Either),
+            Err(StepError::TooLarge(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn plateaus_keep_the_direction() {
+        let policy = SafetyPolicy {
+            allow_plateaus: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.check_step(4, 4, Direction::Decreasing),
+            Ok(Direction::Decreasing)
+        );
+    }
+
+    #[test]
+    fn loads_partial_toml() {
+        let policy = SafetyPolicy::from_toml("max_step = 5\ndirection = \"increasing\"").unwrap();
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                min_step: 1,
+                max_step: 5,
+                direction: Direction::Increasing,
+                allow_plateaus: false,
+                short_reports: ShortReports::Safe,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_policies() {
+        assert!(SafetyPolicy::from_toml("min_step = 4").is_err());
+        assert!(SafetyPolicy::from_toml("min_step = -1").is_err());
+        assert!(SafetyPolicy::from_toml("direction = \"sideways\"").is_err());
+        assert!(SafetyPolicy::from_toml("max_stepp = 5").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use num_bigint::BigInt;
+
+use crate::instructions::Operation;
+
+/// The integer type results and totals are computed in.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IntWidth {
+    #[value(name = "32")]
+    W32,
+    #[default]
+    #[value(name = "64")]
+    W64,
+    #[value(name = "128")]
+    W128,
+    /// Arbitrary precision, which never overflows.
+    Big,
+}
+
+impl fmt::Display for IntWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntWidth::W32 => write!(f, "32-bit"),
+            IntWidth::W64 => write!(f, "64-bit"),
+            IntWidth::W128 => write!(f, "128-bit"),
+            IntWidth::Big => write!(f, "arbitrary precision"),
+        }
+    }
+}
+
+/// An integer that reports overflow instead of wrapping or panicking.
+pub trait CheckedInteger: Sized + Clone + fmt::Display {
+    const WIDTH: IntWidth;
+
+    fn zero() -> Self;
+    fn from_operand(operand: i32) -> Self;
+    /// Applies `operation` to `self` and `other`, or returns `None` if the result doesn't fit.
+    fn checked_apply(&self, operation: Operation, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_integer {
+    ($($int:ty => $width:ident),*) => {
+        $(
+            impl CheckedInteger for $int {
+                const WIDTH: IntWidth = IntWidth::$width;
+
+                fn zero() -> Self {
+                    return 0;
+                }
+
+                fn from_operand(operand: i32) -> Self {
+                    return operand.into();
+                }
+
+                fn checked_apply(&self, operation: Operation, other: &Self) -> Option<Self> {
+                    return match operation {
+                        Operation::Add => self.checked_add(*other),
+                        Operation::Sub => self.checked_sub(*other),
+                        Operation::Mul => self.checked_mul(*other),
+                    };
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_integer!(i32 => W32, i64 => W64, i128 => W128);
+
+impl CheckedInteger for BigInt {
+    const WIDTH: IntWidth = IntWidth::Big;
+
+    fn zero() -> Self {
+        return BigInt::ZERO;
+    }
+
+    fn from_operand(operand: i32) -> Self {
+        return BigInt::from(operand);
+    }
+
+    fn checked_apply(&self, operation: Operation, other: &Self) -> Option<Self> {
+        return Some(match operation {
+            Operation::Add => self + other,
+            Operation::Sub => self - other,
+            Operation::Mul => self * other,
+        });
+    }
+}
+
+/// Where evaluating memory overflowed: the offset of the instruction whose result, or whose
+/// addition to the total, didn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError {
+    pub offset: usize,
+    pub width: IntWidth,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the instruction at byte {} overflows a {} integer",
+            self.offset, self.width
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_apply_reports_overflow() {
+        assert_eq!(i32::MAX.checked_apply(Operation::Add, &1), None);
+        assert_eq!(i32::MIN.checked_apply(Operation::Sub, &1), None);
+        assert_eq!(
+            (i32::MAX as i64).checked_apply(Operation::Add, &1),
+            Some(i32::MAX as i64 + 1)
+        );
+        assert_eq!(i128::MAX.checked_apply(Operation::Mul, &2), None);
+        let big = BigInt::from(i128::MAX)
+            .checked_apply(Operation::Mul, &BigInt::from(2))
+            .unwrap();
+        assert_eq!(big.to_string(), "340282366920938463463374607431768211454");
+    }
+}
@@ -0,0 +1,85 @@
+use log::debug;
+
+use crate::{
+    arithmetic::{CheckedInteger, OverflowError},
+    instructions::Operation,
+    parser::{Instruction, ParsedInstruction},
+};
+
+/// Keeps a running total of the results of enabled operations, fed one instruction at a time;
+/// toggles only count when parsing conditionals.
+pub struct Evaluator<T> {
+    parse_conditionals: bool,
+    are_processing_operations: bool,
+    total: T,
+}
+
+impl<T: CheckedInteger> Evaluator<T> {
+    pub fn new(parse_conditionals: bool) -> Evaluator<T> {
+        return Evaluator {
+            parse_conditionals,
+            are_processing_operations: true,
+            total: T::zero(),
+        };
+    }
+
+    /// Fails if the instruction's result, or the total after adding it, doesn't fit in a `T`.
+    pub fn add(&mut self, parsed: &ParsedInstruction) -> Result<(), OverflowError> {
+        match &parsed.instruction {
+            Instruction::Apply(operation, operands) => {
+                if self.are_processing_operations {
+                    self.total = operation
+                        .apply::<T>(operands)
+                        .and_then(|result| self.total.checked_apply(Operation::Add, &result))
+                        .ok_or(OverflowError {
+                            offset: parsed.offset,
+                            width: T::WIDTH,
+                        })?;
+                }
+            }
+            Instruction::Enable if self.parse_conditionals => {
+                debug!("Processing operations from byte {}", parsed.offset);
+                self.are_processing_operations = true;
+            }
+            Instruction::Disable if self.parse_conditionals => {
+                debug!("Skipping operations from byte {}", parsed.offset);
+                self.are_processing_operations = false;
+            }
+            Instruction::Enable | Instruction::Disable => {}
+        }
+        return Ok(());
+    }
+
+    pub fn get_total(&self) -> &T {
+        return &self.total;
+    }
+}
+
+/// The totals with and without parsing conditionals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryTotals<T> {
+    pub unconditional: T,
+    pub conditional: T,
+}
+
+impl<T> MemoryTotals<T> {
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> MemoryTotals<U> {
+        return MemoryTotals {
+            unconditional: f(self.unconditional),
+            conditional: f(self.conditional),
+        };
+    }
+}
+
+/// Adds the instruction to a total that hasn't overflowed yet, keeping the first overflow so the
+/// other total can carry on without it.
+pub fn add_unless_overflowed<T: CheckedInteger>(
+    evaluator: &mut Result<Evaluator<T>, OverflowError>,
+    parsed: &ParsedInstruction,
+) {
+    if let Ok(running) = evaluator {
+        if let Err(err) = running.add(parsed) {
+            *evaluator = Err(err);
+        }
+    }
+}
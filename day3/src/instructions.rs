@@ -0,0 +1,252 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::arithmetic::CheckedInteger;
+
+/// What an operation does with its operands, folding them from left to right.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Operation {
+    /// Returns `None` if the result, or any step towards it, doesn't fit in a `T`.
+    pub fn apply<T: CheckedInteger>(&self, operands: &Vec<i32>) -> Option<T> {
+        let mut result = T::from_operand(operands[0]);
+        for operand in &operands[1..] {
+            result = result.checked_apply(*self, &T::from_operand(*operand))?;
+        }
+        return Some(result);
+    }
+}
+
+fn get_default_operand_count() -> usize {
+    return 2;
+}
+
+/// An instruction written as `name(a,b,...)`, taking between `min_operands` and `max_operands`
+/// operands of 1 to 3 digits each.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct OperationSpec {
+    pub name: String,
+    pub apply: Operation,
+    #[serde(default = "get_default_operand_count")]
+    pub min_operands: usize,
+    #[serde(default = "get_default_operand_count")]
+    pub max_operands: usize,
+}
+
+/// The names of instructions written as `name()` that turn operations on or off.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Toggles {
+    pub enable: Vec<String>,
+    pub disable: Vec<String>,
+}
+
+impl Default for Toggles {
+    fn default() -> Self {
+        Toggles {
+            enable: vec!["do".to_string()],
+            disable: vec!["don't".to_string()],
+        }
+    }
+}
+
+/// The instructions the scanner recognises; the default is the puzzle's `mul`, `do` and `don't`.
+///
+/// Instruction sets can be read from a TOML file. Listing any operations replaces `mul`, and
+/// leaving out `[toggles]` keeps `do` and `don't`. Operations may share a name as long as they
+/// take different numbers of operands, so `op(1,2)` and `op(1,2,3)` can do different things:
+///
+/// ```toml
+/// [[operation]]
+/// name = "mul"
+/// apply = "mul"
+/// max_operands = 4
+///
+/// [[operation]]
+/// name = "add"
+/// apply = "add"
+///
+/// [toggles]
+/// enable = ["do", "on"]
+/// disable = ["don't", "off"]
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct InstructionSet {
+    #[serde(rename = "operation")]
+    pub operations: Vec<OperationSpec>,
+    pub toggles: Toggles,
+}
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        InstructionSet {
+            operations: vec![OperationSpec {
+                name: "mul".to_string(),
+                apply: Operation::Mul,
+                min_operands: 2,
+                max_operands: 2,
+            }],
+            toggles: Toggles::default(),
+        }
+    }
+}
+
+impl InstructionSet {
+    pub fn from_toml(data: &str) -> Result<InstructionSet, String> {
+        let instructions: InstructionSet = toml::from_str(data).map_err(|err| err.to_string())?;
+        instructions.validate()?;
+        return Ok(instructions);
+    }
+
+    pub fn load(path: &Path) -> Result<InstructionSet, String> {
+        let data = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        return InstructionSet::from_toml(&data);
+    }
+
+    /// How many bytes after an instruction's start the parser may read to decide if it's there.
+    pub fn get_lookahead(&self) -> usize {
+        let toggles = self.toggles.enable.iter().chain(&self.toggles.disable);
+        let longest_toggle = toggles.map(|name| name.len() + "()".len()).max();
+        // Each operand takes up to 3 digits and a comma or the closing bracket, and telling a
+        // 3 digit operand from a longer one needs one more byte.
+        let longest_operation = self
+            .operations
+            .iter()
+            .map(|spec| spec.name.len() + "(".len() + spec.max_operands * 4 + 1)
+            .max();
+        return longest_toggle.max(longest_operation).unwrap_or(0);
+    }
+
+    /// Fails if a name is invalid, a toggle shares its name with any other instruction, or
+    /// operations sharing a name could both match the same number of operands.
+    pub fn validate(&self) -> Result<(), String> {
+        let toggle_names = self.toggles.enable.iter().chain(&self.toggles.disable);
+        for name in self
+            .operations
+            .iter()
+            .map(|spec| &spec.name)
+            .chain(toggle_names.clone())
+        {
+            if name.is_empty()
+                || name.contains(['(', ')', ','])
+                || name.contains(char::is_whitespace)
+            {
+                return Err(format!(
+                    "'{}' is not a valid instruction name; names must not be empty or contain \
+                     whitespace, brackets or commas",
+                    name
+                ));
+            }
+        }
+        let mut names = HashSet::new();
+        for name in toggle_names {
+            if !names.insert(name) {
+                return Err(format!("'{}' is defined more than once", name));
+            }
+        }
+        for (index, spec) in self.operations.iter().enumerate() {
+            if names.contains(&spec.name) {
+                return Err(format!("'{}' is defined more than once", spec.name));
+            }
+            let overlaps = self.operations[..index].iter().any(|other| {
+                other.name == spec.name
+                    && other.min_operands <= spec.max_operands
+                    && spec.min_operands <= other.max_operands
+            });
+            if overlaps {
+                return Err(format!(
+                    "'{}' is defined more than once for the same number of operands",
+                    spec.name
+                ));
+            }
+        }
+        for spec in &self.operations {
+            if spec.min_operands == 0 {
+                return Err(format!("'{}' must take at least 1 operand", spec.name));
+            }
+            if spec.min_operands > spec.max_operands {
+                return Err(format!(
+                    "'{}' has min_operands ({}) larger than max_operands ({})",
+                    spec.name, spec.min_operands, spec.max_operands
+                ));
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_fold_from_the_left() {
+        assert_eq!(Operation::Add.apply(&vec![2, 4, 6]), Some(12));
+        assert_eq!(Operation::Sub.apply(&vec![10, 4, 3]), Some(3));
+        assert_eq!(Operation::Mul.apply(&vec![2, 4, 5]), Some(40));
+        assert_eq!(Operation::Mul.apply(&vec![7]), Some(7));
+        assert_eq!(Operation::Mul.apply::<i32>(&vec![999, 999, 999, 999]), None);
+        assert_eq!(
+            Operation::Mul.apply::<i64>(&vec![999, 999, 999, 999]),
+            Some(996_005_996_001)
+        );
+    }
+
+    #[test]
+    fn reads_instruction_sets_from_toml() {
+        let instructions = InstructionSet::from_toml(
+            "[[operation]]\nname = \"sum\"\napply = \"add\"\nmax_operands = 3\n\n\
+             [toggles]\nenable = [\"on\"]\ndisable = [\"off\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            instructions,
+            InstructionSet {
+                operations: vec![OperationSpec {
+                    name: "sum".to_string(),
+                    apply: Operation::Add,
+                    min_operands: 2,
+                    max_operands: 3,
+                }],
+                toggles: Toggles {
+                    enable: vec!["on".to_string()],
+                    disable: vec!["off".to_string()],
+                },
+            }
+        );
+        let toggles_only = InstructionSet::from_toml("[toggles]\nenable = [\"go\"]\n").unwrap();
+        assert_eq!(
+            toggles_only.operations,
+            InstructionSet::default().operations
+        );
+        assert_eq!(toggles_only.toggles.disable, vec!["don't".to_string()]);
+    }
+
+    #[test]
+    fn rejects_invalid_instruction_sets() {
+        let invalid = [
+            "[[operation]]\nname = \"mul(\"\napply = \"mul\"\n",
+            "[[operation]]\nname = \"\"\napply = \"mul\"\n",
+            "[[operation]]\nname = \"mul\"\napply = \"mul\"\nmin_operands = 0\n",
+            "[[operation]]\nname = \"mul\"\napply = \"mul\"\nmin_operands = 3\n",
+            "[[operation]]\nname = \"mul\"\napply = \"div\"\n",
+            "[toggles]\nenable = [\"mul\"]\n",
+            "[toggles]\nenable = [\"on\"]\ndisable = [\"on\"]\n",
+            "[[operation]]\nname = \"op\"\napply = \"add\"\n\n\
+             [[operation]]\nname = \"op\"\napply = \"mul\"\nmax_operands = 3\n",
+            "unknown = 1\n",
+        ];
+        for data in invalid {
+            assert!(InstructionSet::from_toml(data).is_err(), "{}", data);
+        }
+    }
+}
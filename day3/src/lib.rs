@@ -0,0 +1,279 @@
+pub mod arithmetic;
+pub mod evaluator;
+pub mod instructions;
+pub mod parser;
+pub mod stream;
+
+use std::io::{self, BufRead};
+
+use aoc_common::{
+    error::{ParseError, SolveError},
+    solver::{Answer, Part, Solver},
+};
+use arithmetic::{CheckedInteger, IntWidth, OverflowError};
+use evaluator::{add_unless_overflowed, Evaluator, MemoryTotals};
+use instructions::InstructionSet;
+use log::info;
+use num_bigint::BigInt;
+use parser::{get_instructions_from_memory, ParsedInstruction, Parser};
+use stream::get_memory_totals_from_reader;
+
+/// How memory is scanned and what its totals are computed in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScannerConfig {
+    pub instructions: InstructionSet,
+    pub int_width: IntWidth,
+}
+
+/// Runs `$body` with `$int` standing for the integer type of `$width`.
+macro_rules! with_int_type {
+    ($width:expr, $int:ident => $body:expr) => {
+        match $width {
+            IntWidth::W32 => {
+                type $int = i32;
+                $body
+            }
+            IntWidth::W64 => {
+                type $int = i64;
+                $body
+            }
+            IntWidth::W128 => {
+                type $int = i128;
+                $body
+            }
+            IntWidth::Big => {
+                type $int = BigInt;
+                $body
+            }
+        }
+    };
+}
+
+/// The total honouring the toggles or not, formatted for display.
+pub fn get_total(
+    memory: &str,
+    config: &ScannerConfig,
+    parse_conditionals: bool,
+) -> Result<Answer, OverflowError> {
+    return with_int_type!(config.int_width, T => {
+        get_total_memory_saved::<T>(memory, &config.instructions, parse_conditionals)
+            .map(|total| total.to_string())
+    });
+}
+
+/// Both totals, formatted for display, from a single pass over memory; each fails on its own.
+pub fn get_totals(
+    memory: &str,
+    config: &ScannerConfig,
+) -> MemoryTotals<Result<Answer, OverflowError>> {
+    return with_int_type!(config.int_width, T => {
+        get_memory_totals::<T>(memory, &config.instructions)
+            .map(|total| total.map(|total| total.to_string()))
+    });
+}
+
+/// Like [`get_totals`], reading `reader` a buffer at a time so it's never held in memory whole.
+pub fn get_totals_from_reader<R: BufRead>(
+    reader: R,
+    config: &ScannerConfig,
+) -> io::Result<MemoryTotals<Result<Answer, OverflowError>>> {
+    return with_int_type!(config.int_width, T => {
+        get_memory_totals_from_reader::<T, R>(reader, &config.instructions).map(|totals| {
+            totals.map(|total| total.map(|total| total.to_string()))
+        })
+    });
+}
+
+/// Sums the results of every enabled operation; toggles only count when parsing conditionals.
+///
+/// Fails at the first instruction whose result, or the total after adding it, doesn't fit in a
+/// `T`.
+pub fn get_total_memory_saved<T: CheckedInteger>(
+    memory: &str,
+    instructions: &InstructionSet,
+    parse_conditionals: bool,
+) -> Result<T, OverflowError> {
+    let mut evaluator = Evaluator::<T>::new(parse_conditionals);
+    for parsed in Parser::new(memory, instructions) {
+        evaluator.add(&parsed)?;
+    }
+
+    let total = evaluator.get_total().clone();
+    info!("Total memory saved: {}", total);
+    return Ok(total);
+}
+
+/// Computes both totals in a single pass over memory; one overflowing doesn't stop the other.
+pub fn get_memory_totals<T: CheckedInteger>(
+    memory: &str,
+    instructions: &InstructionSet,
+) -> MemoryTotals<Result<T, OverflowError>> {
+    let mut unconditional = Ok(Evaluator::<T>::new(false));
+    let mut conditional = Ok(Evaluator::<T>::new(true));
+    for parsed in Parser::new(memory, instructions) {
+        add_unless_overflowed(&mut unconditional, &parsed);
+        add_unless_overflowed(&mut conditional, &parsed);
+    }
+
+    let totals = MemoryTotals {
+        unconditional,
+        conditional,
+    }
+    .map(|evaluator| evaluator.map(|evaluator| evaluator.get_total().clone()));
+    if let (Ok(unconditional), Ok(conditional)) = (&totals.unconditional, &totals.conditional) {
+        info!(
+            "Total memory saved: {} unconditionally, {} parsing conditionals",
+            unconditional, conditional
+        );
+    }
+    return totals;
+}
+
+/// Parses memory into its instructions; part one totals every operation and part two honours the
+/// toggles, each in the configured integer width.
+#[derive(Default)]
+pub struct Day3 {
+    pub config: ScannerConfig,
+}
+
+impl Day3 {
+    /// The total of already parsed instructions, formatted for display.
+    fn get_total_from_instructions(
+        &self,
+        instructions: &Vec<ParsedInstruction>,
+        parse_conditionals: bool,
+    ) -> Result<Answer, OverflowError> {
+        return with_int_type!(self.config.int_width, T => {
+            let mut evaluator = Evaluator::<T>::new(parse_conditionals);
+            for parsed in instructions {
+                evaluator.add(parsed)?;
+            }
+            let total = evaluator.get_total().to_string();
+            info!("Total memory saved: {}", total);
+            Ok(total)
+        });
+    }
+}
+
+impl Solver for Day3 {
+    type Input = Vec<ParsedInstruction>;
+
+    const DAY: u32 = 3;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return Ok(get_instructions_from_memory(
+            raw_data,
+            &self.config.instructions,
+        ));
+    }
+
+    /// `None` if the total overflows; [`Solver::try_solve`] says where.
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        return self.get_total_from_instructions(input, false).ok();
+    }
+
+    /// `None` if the total overflows; [`Solver::try_solve`] says where.
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        return self.get_total_from_instructions(input, true).ok();
+    }
+
+    /// Fails if the part's total overflows, naming the instruction where it does.
+    fn try_solve(&self, input: &Self::Input, part: Part) -> Result<Option<Answer>, SolveError> {
+        return self
+            .get_total_from_instructions(input, part == Part::Two)
+            .map(Some)
+            .map_err(|err| SolveError::new(err.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn part_one_sample() {
+        let memory = Day3::default().parse(TEST_CASE).unwrap();
+        assert_eq!(Day3::default().part_one(&memory), Some("161".to_string()));
+    }
+
+    #[test]
+    fn part_two_sample() {
+        let memory = Day3::default().parse(TEST_CASE).unwrap();
+        assert_eq!(Day3::default().part_two(&memory), Some("48".to_string()));
+    }
+
+    #[test]
+    fn total_follows_the_config() {
+        let config = ScannerConfig {
+            instructions: InstructionSet::from_toml(
+                "[[operation]]\nname = \"mul\"\napply = \"mul\"\n\n\
+                 [[operation]]\nname = \"sub\"\napply = \"sub\"\n\n\
+                 [toggles]\nenable = [\"on\"]\ndisable = [\"off\"]\n",
+            )
+            .unwrap(),
+            ..ScannerConfig::default()
+        };
+        let memory = "mul(2,4)sub(9,2)off()mul(5,5)don't()on()sub(1,6)";
+        assert_eq!(get_total(memory, &config, false), Ok("35".to_string()));
+        assert_eq!(get_total(memory, &config, true), Ok("10".to_string()));
+        assert_eq!(
+            get_totals(memory, &config),
+            MemoryTotals {
+                unconditional: Ok("35".to_string()),
+                conditional: Ok("10".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn overflow_only_fails_its_own_part() {
+        let mut day3 = Day3 {
+            config: ScannerConfig {
+                instructions: InstructionSet::from_toml(
+                    "[[operation]]\nname = \"mul\"\napply = \"mul\"\nmax_operands = 14\n",
+                )
+                .unwrap(),
+                int_width: IntWidth::W32,
+            },
+        };
+        let memory = "mul(2,2)\ndon't()mul(999,999,999,999)do()mul(3,3)";
+        let instructions = day3.parse(memory).unwrap();
+        assert_eq!(day3.part_one(&instructions), None);
+        assert_eq!(day3.part_two(&instructions), Some("13".to_string()));
+        assert_eq!(
+            day3.try_solve(&instructions, Part::One),
+            Err(SolveError::new(
+                "the instruction at byte 16 overflows a 32-bit integer"
+            ))
+        );
+        assert_eq!(
+            day3.try_solve(&instructions, Part::Two),
+            Ok(Some("13".to_string()))
+        );
+
+        day3.config.int_width = IntWidth::W64;
+        assert_eq!(
+            day3.part_one(&instructions),
+            Some("996005996014".to_string())
+        );
+
+        let instructions = day3
+            .parse(&format!("mul({})", ["999"; 7].join(",")))
+            .unwrap();
+        assert_eq!(day3.part_one(&instructions), None);
+        day3.config.int_width = IntWidth::W128;
+        assert!(day3.part_one(&instructions).is_some());
+
+        let instructions = day3
+            .parse(&format!("mul({})", ["999"; 14].join(",")))
+            .unwrap();
+        assert_eq!(day3.part_one(&instructions), None);
+        day3.config.int_width = IntWidth::Big;
+        assert_eq!(
+            day3.part_one(&instructions),
+            Some(BigInt::from(999).pow(14).to_string())
+        );
+    }
+}
@@ -1,94 +1,67 @@
-#[path = "./utils/cliargs.rs"]
-mod cliargs;
+use std::{path::PathBuf, process};
 
-#[path = "./utils/file.rs"]
-mod file;
-
-use cliargs::get_parse_conditionals;
-use cliargs::set_parse_conditionals;
+use aoc_common::{
+    cli::{InputArgs, RunArgs},
+    error::SolveError,
+    solver::{self, Part, Solver},
+};
 use clap::Parser;
-use regex::{Captures, Regex};
-
-#[derive(Debug)]
-struct Multiplication {
-    operands: Vec<i32>,
-}
+use day3::{
+    arithmetic::IntWidth, get_totals_from_reader, instructions::InstructionSet, Day3, ScannerConfig,
+};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 pub struct Cli {
-    #[arg(short, long, value_name = "FILE", required = true)]
-    file: String,
-    #[arg(long)]
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    run: RunArgs,
+    /// Honour do()/don't() instructions; the same as `--part 2`.
+    #[arg(long, conflicts_with = "part")]
     parse_conditionals: bool,
+    /// Read the instructions to scan for from this TOML file instead of mul, do and don't.
+    #[arg(long, value_name = "FILE")]
+    instructions: Option<PathBuf>,
+    /// The integer width totals are computed in; overflowing it is an error.
+    #[arg(long, value_enum, value_name = "WIDTH", default_value_t = IntWidth::W64)]
+    int_width: IntWidth,
+    /// Scan each file a buffer at a time, finding both answers in one pass without loading it whole.
+    #[arg(long, conflicts_with_all = ["bench", "check", "record"])]
+    stream: bool,
 }
 
 fn main() {
-    let cli = Cli::parse();
-    let file_path = cli.file;
-    set_parse_conditionals(cli.parse_conditionals);
-    let raw_data = file::load_file(&file_path);
-    let multiplications = get_multiplications_from_string(&raw_data);
-    let products = compute_multiplications(&multiplications);
-    let total = compute_sum(&products);
-
-    println!("Total memory saved: {}", total)
-}
-
-fn get_multiplications_from_string(data: &String) -> Vec<Multiplication> {
-    let parse_conditionals = get_parse_conditionals();
-    let regex_pattern = if parse_conditionals {
-        r"mul\(([0-9]*,[0-9]*)\)|do\(\)|don[']t\(\)"
-    } else {
-        r"mul\(([0-9]*,[0-9]*)\)"
-    };
-    let regex: Regex = Regex::new(regex_pattern).unwrap();
-    let mut multiplications: Vec<Multiplication> = Vec::new();
-    let captured_instructions: Vec<Captures> = regex.captures_iter(data).collect();
-    println!("{:#?}", &captured_instructions);
-
-    let mut are_processing_mul = true;
-    for i in 0..captured_instructions.len() {
-        if captured_instructions[i][0].contains("mul") && are_processing_mul {
-            multiplications.push(Multiplication {
-                operands: captured_instructions[i][1]
-                    .to_string()
-                    .split(",")
-                    .map(|n| n.parse::<i32>().unwrap())
-                    .collect(),
-            });
-        }
-        if captured_instructions[i][0].contains("do()") {
-            println!("Processing 'mul' operations");
-            are_processing_mul = true;
-        }
-        if captured_instructions[i][0].contains("don't()") {
-            println!("Skipping 'mul' operations");
-            are_processing_mul = false;
-        }
+    let mut cli = Cli::parse();
+    if cli.parse_conditionals {
+        cli.run.part = Some(Part::Two);
     }
-
-    println!("{:#?}", multiplications);
-    return multiplications;
-}
-
-fn compute_multiplications(multiplications: &Vec<Multiplication>) -> Vec<i32> {
-    let mut products = Vec::new();
-    for multiplication in multiplications {
-        let mut product = 1;
-        for operand in &multiplication.operands {
-            product *= operand;
-        }
-        products.push(product);
-    }
-    println!("{:#?}", products);
-    return products;
-}
-
-fn compute_sum(products: &Vec<i32>) -> i32 {
-    let mut total = 0;
-    for product in products {
-        total += product;
+    let instructions = match &cli.instructions {
+        Some(path) => match InstructionSet::load(path) {
+            Ok(instructions) => instructions,
+            Err(err) => {
+                eprintln!("Invalid instruction set '{}': {}", path.display(), err);
+                process::exit(1);
+            }
+        },
+        None => InstructionSet::default(),
+    };
+    let day3 = Day3 {
+        config: ScannerConfig {
+            instructions,
+            int_width: cli.int_width,
+        },
+    };
+    if cli.stream {
+        solver::run_streaming(Day3::DAY, &cli.input.files, &cli.run, |reader| {
+            get_totals_from_reader(reader, &day3.config).map(|totals| {
+                let totals =
+                    totals.map(|total| total.map_err(|err| SolveError::new(err.to_string())));
+                (totals.unconditional, totals.conditional)
+            })
+        });
+        return;
     }
-    return total;
+    let puzzle_inputs = cli.input.load();
+    solver::run(&day3, &puzzle_inputs, &cli.run);
 }
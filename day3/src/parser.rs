@@ -0,0 +1,308 @@
+use crate::instructions::{InstructionSet, Operation, OperationSpec};
+
+/// An instruction found in corrupted memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// An operation and its operands, such as `Apply(Mul, [2, 4])` for `mul(2,4)`.
+    Apply(Operation, Vec<i32>),
+    /// One of the toggles that turns operations on, such as `do()`.
+    Enable,
+    /// One of the toggles that turns operations off, such as `don't()`.
+    Disable,
+}
+
+/// An instruction and the byte offset in memory where it starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedInstruction {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+const MAX_OPERAND_DIGITS: usize = 3;
+
+/// Scans corrupted memory for the instructions in a set, skipping everything that isn't one
+/// exactly.
+///
+/// An instruction can start inside the remains of a failed one, so `mul(mul(2,3)` yields
+/// `mul(2,3)` at offset 4.
+pub struct Parser<'a> {
+    memory: &'a [u8],
+    instructions: &'a InstructionSet,
+    position: usize,
+    start_limit: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(memory: &'a str, instructions: &'a InstructionSet) -> Parser<'a> {
+        return Parser::from_bytes(memory.as_bytes(), instructions, memory.len());
+    }
+
+    /// A parser that only looks for instructions starting before `start_limit`, though they may
+    /// end after it.
+    pub fn from_bytes(
+        memory: &'a [u8],
+        instructions: &'a InstructionSet,
+        start_limit: usize,
+    ) -> Parser<'a> {
+        return Parser {
+            memory,
+            instructions,
+            position: 0,
+            start_limit,
+        };
+    }
+
+    /// The offset the next instruction will be looked for from.
+    pub fn get_position(&self) -> usize {
+        return self.position;
+    }
+
+    /// Tries to read an instruction starting at `start`, returning it and the offset just past it.
+    ///
+    /// Names can't contain brackets, and operations sharing a name take different numbers of
+    /// operands, so at most one instruction in the set can match.
+    fn get_instruction_at(&self, start: usize) -> Option<(Instruction, usize)> {
+        let toggles = &self.instructions.toggles;
+        for name in &toggles.enable {
+            if let Some(end) = self.get_toggle_end(start, name) {
+                return Some((Instruction::Enable, end));
+            }
+        }
+        for name in &toggles.disable {
+            if let Some(end) = self.get_toggle_end(start, name) {
+                return Some((Instruction::Disable, end));
+            }
+        }
+        for spec in &self.instructions.operations {
+            if let Some((operands, end)) = self.get_operation_at(start, spec) {
+                return Some((Instruction::Apply(spec.apply, operands), end));
+            }
+        }
+        return None;
+    }
+
+    /// Tries to read `spec` starting at `start`, returning its operands and the offset just past
+    /// it.
+    fn get_operation_at(&self, start: usize, spec: &OperationSpec) -> Option<(Vec<i32>, usize)> {
+        let mut end = self.get_literal_end(start, &spec.name)?;
+        end = self.get_literal_end(end, "(")?;
+        let mut operands = Vec::new();
+        loop {
+            let (operand, operand_end) = self.get_operand(end)?;
+            operands.push(operand);
+            if operands.len() < spec.max_operands {
+                if let Some(comma_end) = self.get_literal_end(operand_end, ",") {
+                    end = comma_end;
+                    continue;
+                }
+            }
+            end = self.get_literal_end(operand_end, ")")?;
+            break;
+        }
+        if operands.len() < spec.min_operands {
+            return None;
+        }
+        return Some((operands, end));
+    }
+
+    fn get_toggle_end(&self, start: usize, name: &str) -> Option<usize> {
+        let end = self.get_literal_end(start, name)?;
+        return self.get_literal_end(end, "()");
+    }
+
+    fn get_literal_end(&self, start: usize, literal: &str) -> Option<usize> {
+        let end = start + literal.len();
+        if self.memory.get(start..end)? == literal.as_bytes() {
+            return Some(end);
+        }
+        return None;
+    }
+
+    fn get_operand(&self, start: usize) -> Option<(i32, usize)> {
+        let digits = self.memory[start..]
+            .iter()
+            .take(MAX_OPERAND_DIGITS + 1)
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits == 0 || digits > MAX_OPERAND_DIGITS {
+            return None;
+        }
+        let mut operand = 0;
+        for byte in &self.memory[start..start + digits] {
+            operand = operand * 10 + (byte - b'0') as i32;
+        }
+        return Some((operand, start + digits));
+    }
+}
+
+impl Iterator for Parser<'_> {
+    type Item = ParsedInstruction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.start_limit {
+            let start = self.position;
+            if let Some((instruction, end)) = self.get_instruction_at(start) {
+                self.position = end;
+                return Some(ParsedInstruction {
+                    offset: start,
+                    instruction,
+                });
+            }
+            self.position += 1;
+        }
+        return None;
+    }
+}
+
+/// Every instruction from the set in corrupted memory, in order.
+pub fn get_instructions_from_memory(
+    memory: &str,
+    instructions: &InstructionSet,
+) -> Vec<ParsedInstruction> {
+    return Parser::new(memory, instructions).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_instructions(memory: &str) -> Vec<Instruction> {
+        return get_instructions_from_memory(memory, &InstructionSet::default())
+            .into_iter()
+            .map(|parsed| parsed.instruction)
+            .collect();
+    }
+
+    fn mul(a: i32, b: i32) -> Instruction {
+        return Instruction::Apply(Operation::Mul, vec![a, b]);
+    }
+
+    #[test]
+    fn finds_instructions_with_offsets() {
+        let instructions = get_instructions_from_memory(
+            "xmul(2,4)&don't()_do()mul(5,5)",
+            &InstructionSet::default(),
+        );
+        assert_eq!(
+            instructions,
+            vec![
+                ParsedInstruction {
+                    offset: 1,
+                    instruction: mul(2, 4)
+                },
+                ParsedInstruction {
+                    offset: 10,
+                    instruction: Instruction::Disable
+                },
+                ParsedInstruction {
+                    offset: 18,
+                    instruction: Instruction::Enable
+                },
+                ParsedInstruction {
+                    offset: 22,
+                    instruction: mul(5, 5)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn operands_need_one_to_three_digits() {
+        assert_eq!(get_instructions("mul(,)mul(1,)mul(,2)"), vec![]);
+        assert_eq!(get_instructions("mul(1234,5)mul(5,1234)"), vec![]);
+        assert_eq!(
+            get_instructions("mul(999,0)mul(007,12)"),
+            vec![mul(999, 0), mul(7, 12)]
+        );
+    }
+
+    #[test]
+    fn rejects_near_misses() {
+        let memory = "mul[3,7]mul(32,64]mul ( 2,4 )mul(2, 4)do ()don't(do_not()MUL(2,2)";
+        assert_eq!(get_instructions(memory), vec![]);
+    }
+
+    #[test]
+    fn restarts_inside_failed_instructions() {
+        assert_eq!(
+            get_instructions_from_memory("mul(mul(2,3)", &InstructionSet::default()),
+            vec![ParsedInstruction {
+                offset: 4,
+                instruction: mul(2, 3)
+            }]
+        );
+        assert_eq!(get_instructions("don't(do()"), vec![Instruction::Enable]);
+    }
+
+    #[test]
+    fn handles_multibyte_characters() {
+        assert_eq!(get_instructions("é mul(2,2)ü"), vec![mul(2, 2)]);
+    }
+
+    #[test]
+    fn finds_configured_instructions() {
+        let instructions = InstructionSet::from_toml(
+            "[[operation]]\nname = \"mul\"\napply = \"mul\"\nmax_operands = 3\n\n\
+             [[operation]]\nname = \"add\"\napply = \"add\"\n\n\
+             [[operation]]\nname = \"sub\"\napply = \"sub\"\nmin_operands = 1\n\n\
+             [toggles]\nenable = [\"on\"]\ndisable = [\"off\"]\n",
+        )
+        .unwrap();
+        let memory = "mul(1,2,3)mul(1,2,3,4)add(4,5)add(4,5,6)sub(7)on()off()do()don't()";
+        let found: Vec<Instruction> = get_instructions_from_memory(memory, &instructions)
+            .into_iter()
+            .map(|parsed| parsed.instruction)
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                Instruction::Apply(Operation::Mul, vec![1, 2, 3]),
+                Instruction::Apply(Operation::Add, vec![4, 5]),
+                Instruction::Apply(Operation::Sub, vec![7]),
+                Instruction::Enable,
+                Instruction::Disable,
+            ]
+        );
+    }
+
+    #[test]
+    fn operand_counts_follow_the_spec() {
+        let instructions = InstructionSet {
+            operations: vec![OperationSpec {
+                name: "mul".to_string(),
+                apply: Operation::Mul,
+                min_operands: 2,
+                max_operands: 3,
+            }],
+            ..InstructionSet::default()
+        };
+        let memory = "mul(2)mul(2,3)mul(2,3,4)mul(2,3,4,5)mul(2,3,)";
+        let operands: Vec<usize> = get_instructions_from_memory(memory, &instructions)
+            .iter()
+            .map(|parsed| match &parsed.instruction {
+                Instruction::Apply(_, operands) => operands.len(),
+                _ => 0,
+            })
+            .collect();
+        assert_eq!(operands, vec![2, 3]);
+    }
+
+    #[test]
+    fn operations_sharing_a_name_read_by_operand_count() {
+        let instructions = InstructionSet::from_toml(
+            "[[operation]]\nname = \"op\"\napply = \"add\"\n\n\
+             [[operation]]\nname = \"op\"\napply = \"mul\"\nmin_operands = 3\nmax_operands = 3\n",
+        )
+        .unwrap();
+        assert_eq!(
+            get_instructions_from_memory("op(1,2)op(1,2,3)", &instructions)
+                .into_iter()
+                .map(|parsed| parsed.instruction)
+                .collect::<Vec<Instruction>>(),
+            vec![
+                Instruction::Apply(Operation::Add, vec![1, 2]),
+                Instruction::Apply(Operation::Mul, vec![1, 2, 3])
+            ]
+        );
+    }
+}
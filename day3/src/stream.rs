@@ -0,0 +1,176 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+use crate::{
+    arithmetic::{CheckedInteger, OverflowError},
+    evaluator::{add_unless_overflowed, Evaluator, MemoryTotals},
+    instructions::InstructionSet,
+    parser::{ParsedInstruction, Parser},
+};
+
+/// Scans memory from a reader a buffer at a time, yielding the same instructions and offsets as
+/// [`Parser`] would for the whole of it.
+///
+/// Only the bytes that might still start an instruction are kept between buffers, so memory use
+/// doesn't grow with the size of the input.
+pub struct StreamParser<'a, R> {
+    reader: R,
+    instructions: &'a InstructionSet,
+    lookahead: usize,
+    /// Bytes read but not yet scanned past, starting at `window_offset` in the stream.
+    window: Vec<u8>,
+    window_offset: usize,
+    parsed: VecDeque<ParsedInstruction>,
+    is_finished: bool,
+}
+
+impl<'a, R: BufRead> StreamParser<'a, R> {
+    pub fn new(reader: R, instructions: &'a InstructionSet) -> StreamParser<'a, R> {
+        return StreamParser {
+            reader,
+            instructions,
+            lookahead: instructions.get_lookahead(),
+            window: Vec::new(),
+            window_offset: 0,
+            parsed: VecDeque::new(),
+            is_finished: false,
+        };
+    }
+
+    /// Reads the next buffer and scans every position far enough from its end for the parser to
+    /// see whole instructions; at the end of the stream, scans the rest.
+    fn read_buffer(&mut self) -> io::Result<()> {
+        let buffer = match self.reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let length = buffer.len();
+        self.is_finished = length == 0;
+        self.window.extend_from_slice(buffer);
+        self.reader.consume(length);
+
+        let start_limit = if self.is_finished {
+            self.window.len()
+        } else {
+            self.window.len().saturating_sub(self.lookahead)
+        };
+        let mut parser = Parser::from_bytes(&self.window, self.instructions, start_limit);
+        for mut parsed in parser.by_ref() {
+            parsed.offset += self.window_offset;
+            self.parsed.push_back(parsed);
+        }
+        let scanned = parser.get_position();
+        self.window.drain(..scanned);
+        self.window_offset += scanned;
+        return Ok(());
+    }
+}
+
+impl<R: BufRead> Iterator for StreamParser<'_, R> {
+    type Item = io::Result<ParsedInstruction>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(parsed) = self.parsed.pop_front() {
+                return Some(Ok(parsed));
+            }
+            if self.is_finished {
+                return None;
+            }
+            if let Err(err) = self.read_buffer() {
+                self.is_finished = true;
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+/// Computes both totals in a single pass over `reader`; one overflowing doesn't stop the other,
+/// but failing to read stops both.
+pub fn get_memory_totals_from_reader<T: CheckedInteger, R: BufRead>(
+    reader: R,
+    instructions: &InstructionSet,
+) -> io::Result<MemoryTotals<Result<T, OverflowError>>> {
+    let mut unconditional = Ok(Evaluator::<T>::new(false));
+    let mut conditional = Ok(Evaluator::<T>::new(true));
+    for parsed in StreamParser::new(reader, instructions) {
+        let parsed = parsed?;
+        add_unless_overflowed(&mut unconditional, &parsed);
+        add_unless_overflowed(&mut conditional, &parsed);
+    }
+    let totals = MemoryTotals {
+        unconditional,
+        conditional,
+    };
+    return Ok(totals.map(|evaluator| evaluator.map(|evaluator| evaluator.get_total().clone())));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+    use crate::{arithmetic::IntWidth, parser::get_instructions_from_memory};
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn matches_the_parser_for_any_buffer_size() {
+        let instructions = InstructionSet::from_toml(
+            "[[operation]]\nname = \"mul\"\napply = \"mul\"\nmax_operands = 3\n",
+        )
+        .unwrap();
+        let memory = format!(
+            "{}mul(1234,5)mul(mul(2,3)mul(1,2,3)mul(1,2,3,4)don't(do()mul(999,999,999)",
+            TEST_CASE
+        );
+        let expected = get_instructions_from_memory(&memory, &instructions);
+        for capacity in 1..=32 {
+            let reader = BufReader::with_capacity(capacity, memory.as_bytes());
+            let streamed: Vec<ParsedInstruction> = StreamParser::new(reader, &instructions)
+                .collect::<io::Result<_>>()
+                .unwrap();
+            assert_eq!(streamed, expected, "buffer capacity {}", capacity);
+        }
+    }
+
+    #[test]
+    fn keeps_toggle_state_across_buffers() {
+        let memory = format!(
+            "don't(){}mul(2,2)do(){}mul(3,3)",
+            "x".repeat(100),
+            "y".repeat(100)
+        );
+        let reader = BufReader::with_capacity(16, memory.as_bytes());
+        let totals =
+            get_memory_totals_from_reader::<i64, _>(reader, &InstructionSet::default()).unwrap();
+        assert_eq!(
+            totals,
+            MemoryTotals {
+                unconditional: Ok(13),
+                conditional: Ok(9),
+            }
+        );
+    }
+
+    #[test]
+    fn reports_overflow_at_its_offset_for_its_own_total() {
+        let memory = format!("don't(){}", "mul(999,999)".repeat(3000));
+        let reader = BufReader::with_capacity(7, memory.as_bytes());
+        let totals =
+            get_memory_totals_from_reader::<i32, _>(reader, &InstructionSet::default()).unwrap();
+        assert_eq!(
+            totals,
+            MemoryTotals {
+                unconditional: Err(OverflowError {
+                    offset: 7 + 2151 * 12,
+                    width: IntWidth::W32
+                }),
+                conditional: Ok(0),
+            }
+        );
+    }
+}
@@ -0,0 +1,89 @@
+pub mod render;
+pub mod template;
+pub mod word_search;
+
+use aoc_common::{
+    error::ParseError,
+    grid::{get_grid_from_raw_data, Grid},
+    solver::{Answer, Solver},
+};
+use log::{info, trace};
+use template::{Template, TemplateSearch};
+use word_search::{get_default_workers, WordSearch};
+
+pub struct Day4 {
+    /// How many threads to search with.
+    pub workers: usize,
+}
+
+impl Default for Day4 {
+    fn default() -> Self {
+        Day4 {
+            workers: get_default_workers(),
+        }
+    }
+}
+
+impl Solver for Day4 {
+    type Input = Grid;
+
+    const DAY: u32 = 4;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let data = get_grid_from_raw_data(raw_data);
+        trace!("Input 2D Vector:\n{:?}", data);
+        return Ok(data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let xmas_count = get_xmas_count_from_matrix(input, self.workers);
+        info!(
+            "The Word Search Found: {} instances of the word 'XMAS'",
+            xmas_count
+        );
+        return Some(xmas_count.to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let x_mas_count = get_x_mas_count_from_matrix(input, self.workers);
+        info!(
+            "The X-MAS  Search Found: {} instances of an 'X-MAS'",
+            x_mas_count
+        );
+        return Some(x_mas_count.to_string());
+    }
+}
+
+fn get_xmas_count_from_matrix(data: &Vec<Vec<char>>, workers: usize) -> usize {
+    let search = WordSearch::new(&vec!["XMAS".to_string()])
+        .expect("XMAS is not empty")
+        .with_workers(workers);
+    return search.count_matches(data);
+}
+
+fn get_x_mas_count_from_matrix(data: &Vec<Vec<char>>, workers: usize) -> usize {
+    let x_mas = Template::new("X-MAS", "M.S\n.A.\nM.S", true, false).expect("X-MAS has cells");
+    let search = TemplateSearch::new(vec![x_mas]).with_workers(workers);
+    return search.count_matches(data)[0];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn x_mas_count_matches_sample() {
+        let grid = Day4::default().parse(TEST_CASE).unwrap();
+        assert_eq!(get_x_mas_count_from_matrix(&grid, 1), 9);
+    }
+
+    #[test]
+    fn sample_answers() {
+        let day4 = Day4::default();
+        let grid = day4.parse(TEST_CASE).unwrap();
+        assert_eq!(day4.part_one(&grid), Some("18".to_string()));
+        assert_eq!(day4.part_two(&grid), Some("9".to_string()));
+    }
+}
@@ -1,221 +1,167 @@
-#[path = "./utils/file.rs"]
-mod file;
-use strum::IntoEnumIterator; // 0.17.1
-use strum_macros::EnumIter; // 0.17.1
-                            //
-use clap::Parser;
 use std::{
-    fmt,
-    sync::atomic::{AtomicU32, Ordering},
-    thread::{self},
-    usize,
+    io::{self, IsTerminal},
+    path::PathBuf,
+    process,
 };
 
-static XMAS_COUNT: AtomicU32 = AtomicU32::new(0);
-
-pub fn get_xmas_count() -> u32 {
-    XMAS_COUNT.load(Ordering::Relaxed)
-}
-
-pub fn set_xmas_count_conditionals(count: u32) {
-    XMAS_COUNT.store(count, Ordering::Relaxed);
-}
-
-pub fn add_xmas_count(count: u32) -> u32 {
-    XMAS_COUNT.fetch_add(count, Ordering::SeqCst)
-}
+use aoc_common::{
+    cli::{InputArgs, RunArgs},
+    file::PuzzleInputs,
+    report::OutputFormat,
+    solver::{self, Solver},
+};
+use clap::Parser;
+use day4::{
+    render::{get_coverage, get_rendered_json, render_grid, Highlight},
+    template::{get_template_count_json, TemplateSearch},
+    word_search::{get_default_workers, Match, WordSearch},
+    Day4,
+};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 pub struct Cli {
-    #[arg(short, long, value_name = "FILE", required = true)]
-    file: String,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    run: RunArgs,
+    /// List every place this word reads in any of the eight directions instead of printing the
+    /// answers; the flag may be repeated.
+    #[arg(
+        short,
+        long,
+        value_name = "WORD",
+        conflicts_with_all = ["part", "bench", "check", "record"]
+    )]
+    word: Vec<String>,
+    /// Print the grid with the letters of every match picked out, in colour or with all other
+    /// letters masked, instead of listing the matches; colour is only used for text written to a
+    /// terminal, and masks otherwise.
+    #[arg(long, value_name = "MODE", requires = "word")]
+    highlight: Option<Highlight>,
+    /// Print a separate highlighted grid for each word, listing repeated words once.
+    #[arg(long, requires = "highlight")]
+    per_word: bool,
+    /// Count how often each shape in this TOML file of templates fits in the grid instead of
+    /// printing the answers.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["word", "part", "bench", "check", "record"]
+    )]
+    templates: Option<PathBuf>,
+    /// How many threads to search with; defaults to one per core.
+    #[arg(short, long, value_name = "THREADS", default_value_t = get_default_workers())]
+    jobs: usize,
 }
 
 fn main() {
     let cli = Cli::parse();
-    let file_path = cli.file;
-    let raw_data = file::load_file(&file_path);
-    let mut mut_data = Vec::new();
-
-    for line in raw_data.lines() {
-        let temp_line: Vec<char> = line.chars().collect();
-        mut_data.push(temp_line);
+    let puzzle_inputs = cli.input.load();
+    if let Some(path) = &cli.templates {
+        let search = match TemplateSearch::load(path) {
+            Ok(search) => search.with_workers(cli.jobs),
+            Err(err) => {
+                eprintln!("Invalid templates '{}': {}", path.display(), err);
+                process::exit(1);
+            }
+        };
+        print_template_counts(&search, &puzzle_inputs, &cli.run);
+        return;
     }
-    let data = mut_data;
-
-    println!("Input 2D Vector:\n{:?}", data);
-
-    // part 1
-    let xmas_count = get_xmas_count_from_matrix(&data);
-    println!("\n\nPART 1");
-    println!(
-        "The Word Search Found: {} instances of the word 'XMAS'",
-        xmas_count
-    );
-
-    //part 2
-    let x_mas_count = get_x_mas_count_from_matrix(&data);
-    println!("\n\nPART 2");
-    println!(
-        "The X-MAS  Search Found: {} instances of an 'X-MAS'",
-        x_mas_count
-    );
-}
-fn get_x_mas_count_from_matrix(data: &Vec<Vec<char>>) -> u32 {
-    let mut x_mas_count = 0;
-    for x in 1..data.len() - 1 {
-        for y in 1..data[x].len() - 1 {
-            if data[x][y] == 'A' {
-                let mut chars: [char; 4] = ['x'; 4];
-                let mut has_first_leg = false;
-                let mut has_second_leg = false;
-                chars[0] = data[x - 1][y - 1];
-                chars[1] = data[x - 1][y + 1];
-                chars[2] = data[x + 1][y + 1];
-                chars[3] = data[x + 1][y - 1];
-                if chars[0] == 'M' && chars[2] == 'S' || chars[0] == 'S' && chars[2] == 'M' {
-                    has_first_leg = true;
-                }
-                if has_first_leg {
-                    if chars[1] == 'M' && chars[3] == 'S' || chars[1] == 'S' && chars[3] == 'M' {
-                        has_second_leg = true;
-                    }
-                }
-                if has_first_leg && has_second_leg {
-                    x_mas_count += 1;
-                }
+    if !cli.word.is_empty() {
+        let search = match WordSearch::new(&cli.word) {
+            Ok(search) => search.with_workers(cli.jobs),
+            Err(err) => {
+                eprintln!("Invalid word list: {}", err);
+                process::exit(1);
+            }
+        };
+        match cli.highlight {
+            Some(highlight) => {
+                let shows_colour =
+                    cli.run.format == OutputFormat::Text && io::stdout().is_terminal();
+                let highlight = highlight.for_output(shows_colour);
+                print_highlighted(&search, &puzzle_inputs, &cli.run, highlight, cli.per_word)
             }
+            None => print_matches(&search, &puzzle_inputs, &cli.run),
         }
+        return;
     }
-    return x_mas_count;
+    let day4 = Day4 { workers: cli.jobs };
+    solver::run(&day4, &puzzle_inputs, &cli.run);
 }
-fn get_xmas_count_from_matrix(data: &Vec<Vec<char>>) -> u32 {
-    let remaining_word = "MAS";
-    for x in 0..data.len() {
-        for y in 0..data[x].len() {
-            if data[x][y] == 'X' {
-                search_directions_for_remaining_word(remaining_word, x, y, data);
+
+fn print_matches(search: &WordSearch, puzzle_inputs: &PuzzleInputs, args: &RunArgs) {
+    let words = search.get_words();
+    let parse = |data: &str| Day4::default().parse(data);
+    solver::run_reports(puzzle_inputs, args, parse, |puzzle_input, grid| {
+        for found in search.find_matches(&grid) {
+            let word = &words[found.word];
+            match args.format {
+                OutputFormat::Text => println!("{}", found.to_text(word)),
+                OutputFormat::Json => println!("{}", found.to_json(&puzzle_input.name, word)),
             }
         }
-    }
-    return get_xmas_count();
+    });
 }
 
-fn search_directions_for_remaining_word(
-    remaining_word: &str,
-    start_x: usize,
-    start_y: usize,
-    data: &Vec<Vec<char>>,
+fn print_highlighted(
+    search: &WordSearch,
+    puzzle_inputs: &PuzzleInputs,
+    args: &RunArgs,
+    highlight: Highlight,
+    per_word: bool,
 ) {
-    thread::scope(|s| {
-        for direction in Direction::iter() {
-            let _tbuilder = thread::Builder::new()
-                .name(direction.to_string())
-                .spawn_scoped(s, move || {
-                    let x = start_x.clone();
-                    let y = start_y.clone();
-
-                    println!(
-                        "Dir: {}, Current Position: {},{}",
-                        direction.to_string(),
-                        x,
-                        y
-                    );
-                    let mut word: String = String::from("");
-                    match direction {
-                        Direction::Left => {
-                            if y >= remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x][y - i]);
-                                }
-                            }
-                        }
-                        Direction::Right => {
-                            if y < data[x].len() - remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x][y + i]);
-                                }
-                            }
-                        }
-                        Direction::Up => {
-                            if x >= remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x - i][y]);
-                                }
-                            }
-                        }
-                        Direction::Down => {
-                            if x < data.len() - remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x + i][y]);
-                                }
-                            }
-                        }
-                        Direction::LeftUp => {
-                            if x >= remaining_word.len() && y >= remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x - i][y - i]);
-                                }
-                            }
-                        }
-                        Direction::RightUp => {
-                            if x >= remaining_word.len() && y < data[x].len() - remaining_word.len()
-                            {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x - i][y + i]);
-                                }
-                            }
-                        }
-                        Direction::LeftDown => {
-                            if x < data.len() - remaining_word.len() && y >= remaining_word.len() {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x + i][y - i]);
-                                }
-                            }
-                        }
-                        Direction::RightDown => {
-                            if x < data.len() - remaining_word.len()
-                                && y < data[x].len() - remaining_word.len()
-                            {
-                                for i in 1..=remaining_word.len() {
-                                    word.push(data[x + i][y + i]);
-                                }
-                            }
-                        }
-                    }
-
-                    if word == remaining_word {
-                        add_xmas_count(1);
+    let words = search.get_words();
+    let parse = |data: &str| Day4::default().parse(data);
+    solver::run_reports(puzzle_inputs, args, parse, |puzzle_input, grid| {
+        let matches = search.find_matches(&grid);
+        let mut groups: Vec<(Option<&str>, Vec<Match>)> = Vec::new();
+        if per_word {
+            for (index, word) in words.iter().enumerate() {
+                // Repeated words are only searched for, and matched, as the first of them.
+                if words[..index].contains(word) {
+                    continue;
+                }
+                let word_matches = matches.iter().filter(|found| found.word == index);
+                groups.push((Some(word), word_matches.copied().collect()));
+            }
+        } else {
+            groups.push((None, matches));
+        }
+        for (word, group) in groups {
+            let coverage = get_coverage(&grid, search, &group);
+            let rendered = render_grid(&grid, &coverage, highlight);
+            match args.format {
+                OutputFormat::Text => {
+                    match word {
+                        Some(word) => println!("{}: {}", word, group.len()),
+                        None => println!("matches: {}", group.len()),
                     }
-
-                    println!(
-                        "Dir: {}, found: {}, looking for: {}",
-                        direction.to_string(),
-                        word,
-                        remaining_word
-                    );
-                });
+                    print!("{}", rendered);
+                }
+                OutputFormat::Json => println!(
+                    "{}",
+                    get_rendered_json(&puzzle_input.name, word, group.len(), &rendered)
+                ),
+            }
         }
-    })
+    });
 }
 
-#[derive(Debug, EnumIter)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-    LeftUp,
-    RightUp,
-    LeftDown,
-    RightDown,
-}
-
-impl fmt::Display for Direction {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
-        // or, alternatively:
-        // fmt::Debug::fmt(self, f)
-    }
+fn print_template_counts(search: &TemplateSearch, puzzle_inputs: &PuzzleInputs, args: &RunArgs) {
+    let templates = search.get_templates();
+    let parse = |data: &str| Day4::default().parse(data);
+    solver::run_reports(puzzle_inputs, args, parse, |puzzle_input, grid| {
+        for (template, count) in templates.iter().zip(search.count_matches(&grid)) {
+            match args.format {
+                OutputFormat::Text => println!("{}: {}", template.name, count),
+                OutputFormat::Json => println!(
+                    "{}",
+                    get_template_count_json(&puzzle_input.name, template, count)
+                ),
+            }
+        }
+    });
 }
@@ -0,0 +1,146 @@
+use aoc_common::grid::Grid;
+use clap::ValueEnum;
+use serde_json::json;
+
+use crate::word_search::{Match, WordSearch};
+
+/// How the letters of matched words are picked out when a grid is rendered.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// Colour matched letters with ANSI escape codes, a colour per word.
+    Color,
+    /// Keep matched letters and replace every other letter with `.`.
+    Mask,
+}
+
+impl Highlight {
+    /// Colour is only shown when it can be, on a terminal; elsewhere, such as in a file or JSON,
+    /// the escape codes would get in the way, so matches are masked instead.
+    pub fn for_output(self, shows_colour: bool) -> Highlight {
+        return match self {
+            Highlight::Color if !shows_colour => Highlight::Mask,
+            highlight => highlight,
+        };
+    }
+}
+
+/// The ANSI colours words are shown in, reused in order when there are more words than colours.
+const PALETTE: [&str; 6] = [
+    "\x1b[1;31m",
+    "\x1b[1;32m",
+    "\x1b[1;33m",
+    "\x1b[1;34m",
+    "\x1b[1;35m",
+    "\x1b[1;36m",
+];
+const RESET: &str = "\x1b[0m";
+const MASKED: char = '.';
+
+/// Which word, if any, covers each cell of the grid; where matches overlap, the first one wins.
+pub fn get_coverage(
+    grid: &Grid,
+    search: &WordSearch,
+    matches: &Vec<Match>,
+) -> Vec<Vec<Option<usize>>> {
+    let mut coverage: Vec<Vec<Option<usize>>> =
+        grid.iter().map(|row| vec![None; row.len()]).collect();
+    for found in matches {
+        for (row, column) in search.get_cells(found) {
+            coverage[row][column].get_or_insert(found.word);
+        }
+    }
+    return coverage;
+}
+
+/// Renders the grid a row per line, with the covered cells highlighted.
+pub fn render_grid(
+    grid: &Grid,
+    coverage: &Vec<Vec<Option<usize>>>,
+    highlight: Highlight,
+) -> String {
+    let mut rendered = String::new();
+    for (row, letters) in grid.iter().enumerate() {
+        for (column, letter) in letters.iter().enumerate() {
+            match (coverage[row][column], highlight) {
+                (Some(word), Highlight::Color) => {
+                    rendered.push_str(PALETTE[word % PALETTE.len()]);
+                    rendered.push(*letter);
+                    rendered.push_str(RESET);
+                }
+                (Some(_), Highlight::Mask) => rendered.push(*letter),
+                (None, Highlight::Color) => rendered.push(*letter),
+                (None, Highlight::Mask) => rendered.push(MASKED),
+            }
+        }
+        rendered.push('\n');
+    }
+    return rendered;
+}
+
+/// A rendered grid as a JSON record, with `word` set when it only shows one word's matches.
+pub fn get_rendered_json(input: &str, word: Option<&str>, count: usize, rendered: &str) -> String {
+    return json!({
+        "input": input,
+        "word": word,
+        "count": count,
+        "grid": rendered.lines().collect::<Vec<&str>>(),
+    })
+    .to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::grid::get_grid_from_raw_data;
+
+    #[test]
+    fn masks_unmatched_letters() {
+        let grid = get_grid_from_raw_data("XMASX\nSAMXM\nAAAAA\n");
+        let search = WordSearch::new(&vec!["XMAS".to_string(), "AA".to_string()])
+            .unwrap()
+            .with_workers(1);
+        let matches: Vec<Match> = search
+            .find_matches(&grid)
+            .into_iter()
+            .filter(|found| found.word == 0)
+            .collect();
+        let coverage = get_coverage(&grid, &search, &matches);
+        assert_eq!(
+            render_grid(&grid, &coverage, Highlight::Mask),
+            "XMAS.\nSAMX.\n.....\n"
+        );
+    }
+
+    #[test]
+    fn colours_each_word() {
+        let grid = get_grid_from_raw_data("XMAS\nABCD\n");
+        let search = WordSearch::new(&vec!["XMAS".to_string(), "BC".to_string()]).unwrap();
+        let matches = search.find_matches(&grid);
+        let coverage = get_coverage(&grid, &search, &matches);
+        let rendered = render_grid(&grid, &coverage, Highlight::Color);
+        assert_eq!(
+            rendered.lines().nth(1),
+            Some("A\x1b[1;32mB\x1b[0m\x1b[1;32mC\x1b[0mD")
+        );
+        assert!(rendered.starts_with("\x1b[1;31mX\x1b[0m"));
+    }
+
+    #[test]
+    fn colour_falls_back_to_a_mask() {
+        assert_eq!(Highlight::Color.for_output(true), Highlight::Color);
+        assert_eq!(Highlight::Color.for_output(false), Highlight::Mask);
+        assert_eq!(Highlight::Mask.for_output(true), Highlight::Mask);
+    }
+
+    #[test]
+    fn formats_rendered_grids() {
+        assert_eq!(
+            get_rendered_json("day4.txt", Some("XMAS"), 1, "XMAS\n....\n"),
+            r#"{"count":1,"grid":["XMAS","...."],"input":"day4.txt","word":"XMAS"}"#
+        );
+        assert_eq!(
+            get_rendered_json("day4.txt", None, 0, "..\n"),
+            r#"{"count":0,"grid":[".."],"input":"day4.txt","word":null}"#
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use std::{fs, ops::Range, path::Path};
+
+use aoc_common::grid::Grid;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::word_search::{get_default_workers, scan_row_bands};
+
+/// Matches any letter, as long as the grid has a cell there.
+pub const WILDCARD: char = '.';
+/// Marks a cell that isn't part of the shape, for templates that aren't rectangles.
+pub const GAP: char = ' ';
+
+/// A template in one orientation: the row, column and letter of every cell that is part of it,
+/// relative to the top left corner of its bounding box, with `None` for wildcards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Stencil {
+    cells: Vec<(usize, usize, Option<char>)>,
+}
+
+impl Stencil {
+    /// Moves the cells so the bounding box starts at row and column 0, in a canonical order so
+    /// orientations that look the same compare equal.
+    fn from_offsets(offsets: Vec<(isize, isize, Option<char>)>) -> Stencil {
+        let min_row = offsets.iter().map(|cell| cell.0).min().unwrap_or(0);
+        let min_column = offsets.iter().map(|cell| cell.1).min().unwrap_or(0);
+        let mut cells: Vec<(usize, usize, Option<char>)> = offsets
+            .iter()
+            .map(|(row, column, letter)| {
+                (
+                    (row - min_row) as usize,
+                    (column - min_column) as usize,
+                    *letter,
+                )
+            })
+            .collect();
+        cells.sort();
+        return Stencil { cells };
+    }
+
+    fn transform(&self, transform: impl Fn(isize, isize) -> (isize, isize)) -> Stencil {
+        let offsets = self
+            .cells
+            .iter()
+            .map(|(row, column, letter)| {
+                let (row, column) = transform(*row as isize, *column as isize);
+                (row, column, *letter)
+            })
+            .collect();
+        return Stencil::from_offsets(offsets);
+    }
+
+    fn rotate(&self) -> Stencil {
+        return self.transform(|row, column| (column, -row));
+    }
+
+    fn matches_at(&self, grid: &Grid, row: usize, column: usize) -> bool {
+        return self.cells.iter().all(|(cell_row, cell_column, letter)| {
+            let cell = grid
+                .get(row + cell_row)
+                .and_then(|cells| cells.get(column + cell_column));
+            match (cell, letter) {
+                (Some(cell), Some(letter)) => cell == letter,
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+        });
+    }
+}
+
+/// A 2D shape to look for in a grid, optionally in any rotation or reflection of it.
+///
+/// Shapes are written a row per line: letters must match exactly, `.` matches any letter and a
+/// space isn't part of the shape, so
+///
+/// ```text
+/// M.S
+/// .A.
+/// M.S
+/// ```
+///
+/// with rotations finds every X-MAS. Rotations and reflections that look the same as another
+/// orientation are only searched for once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pub name: String,
+    orientations: Vec<Stencil>,
+}
+
+impl Template {
+    pub fn new(
+        name: &str,
+        shape: &str,
+        rotations: bool,
+        reflections: bool,
+    ) -> Result<Template, String> {
+        let mut offsets = Vec::new();
+        for (row, line) in shape.lines().enumerate() {
+            for (column, letter) in line.chars().enumerate() {
+                match letter {
+                    GAP => {}
+                    WILDCARD => offsets.push((row as isize, column as isize, None)),
+                    letter => offsets.push((row as isize, column as isize, Some(letter))),
+                }
+            }
+        }
+        if offsets.is_empty() {
+            return Err(format!("template '{}' has no cells", name));
+        }
+
+        let stencil = Stencil::from_offsets(offsets);
+        let mut orientations = vec![stencil.clone()];
+        if reflections {
+            orientations.push(stencil.transform(|row, column| (row, -column)));
+            orientations.push(stencil.transform(|row, column| (-row, column)));
+        }
+        if rotations {
+            for i in 0..orientations.len() {
+                let mut rotated = orientations[i].rotate();
+                for _ in 0..3 {
+                    let next = rotated.rotate();
+                    orientations.push(rotated);
+                    rotated = next;
+                }
+            }
+        }
+        let mut distinct: Vec<Stencil> = Vec::new();
+        for orientation in orientations {
+            if !distinct.contains(&orientation) {
+                distinct.push(orientation);
+            }
+        }
+        return Ok(Template {
+            name: name.to_string(),
+            orientations: distinct,
+        });
+    }
+
+    pub fn get_orientation_count(&self) -> usize {
+        return self.orientations.len();
+    }
+}
+
+/// A template as written in a TOML file.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateSpec {
+    pub name: String,
+    pub shape: String,
+    #[serde(default)]
+    pub rotations: bool,
+    #[serde(default)]
+    pub reflections: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TemplateFile {
+    template: Vec<TemplateSpec>,
+}
+
+/// A template found in a grid, with the top left corner of the matching orientation's bounding
+/// box at `row` and `column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateMatch {
+    /// The template's index in the search.
+    pub template: usize,
+    pub orientation: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+/// Counts where each of a list of templates fits in a grid, with every orientation that fits
+/// counting separately.
+pub struct TemplateSearch {
+    templates: Vec<Template>,
+    workers: usize,
+}
+
+impl TemplateSearch {
+    pub fn new(templates: Vec<Template>) -> TemplateSearch {
+        return TemplateSearch {
+            templates,
+            workers: get_default_workers(),
+        };
+    }
+
+    /// Reads templates from a TOML file:
+    ///
+    /// ```toml
+    /// [[template]]
+    /// name = "x-mas"
+    /// shape = """
+    /// M.S
+    /// .A.
+    /// M.S
+    /// """
+    /// rotations = true
+    /// reflections = false
+    /// ```
+    pub fn from_toml(data: &str) -> Result<TemplateSearch, String> {
+        let file: TemplateFile = toml::from_str(data).map_err(|err| err.to_string())?;
+        let mut templates = Vec::new();
+        for spec in file.template {
+            templates.push(Template::new(
+                &spec.name,
+                &spec.shape,
+                spec.rotations,
+                spec.reflections,
+            )?);
+        }
+        return Ok(TemplateSearch::new(templates));
+    }
+
+    pub fn load(path: &Path) -> Result<TemplateSearch, String> {
+        let data = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        return TemplateSearch::from_toml(&data);
+    }
+
+    /// Searches on at most `workers` threads; 0 is taken as 1.
+    pub fn with_workers(mut self, workers: usize) -> TemplateSearch {
+        self.workers = workers.max(1);
+        return self;
+    }
+
+    pub fn get_templates(&self) -> &Vec<Template> {
+        return &self.templates;
+    }
+
+    /// Every match in the grid, ordered by row, then column, then template and orientation.
+    pub fn find_matches(&self, grid: &Grid) -> Vec<TemplateMatch> {
+        let bands = scan_row_bands(grid, self.workers, |rows| {
+            let mut matches = Vec::new();
+            self.visit_matches(grid, rows, |found| matches.push(found));
+            matches
+        });
+        return bands.concat();
+    }
+
+    /// How many times each template fits in the grid, in the order of the templates.
+    pub fn count_matches(&self, grid: &Grid) -> Vec<usize> {
+        let bands = scan_row_bands(grid, self.workers, |rows| {
+            let mut counts = vec![0; self.templates.len()];
+            self.visit_matches(grid, rows, |found| counts[found.template] += 1);
+            counts
+        });
+        let mut counts = vec![0; self.templates.len()];
+        for band in bands {
+            for (template, count) in band.iter().enumerate() {
+                counts[template] += count;
+            }
+        }
+        return counts;
+    }
+
+    /// The grid cells a match covers, as rows and columns.
+    pub fn get_cells(&self, found: &TemplateMatch) -> Vec<(usize, usize)> {
+        let stencil = &self.templates[found.template].orientations[found.orientation];
+        return stencil
+            .cells
+            .iter()
+            .map(|(row, column, _)| (found.row + row, found.column + column))
+            .collect();
+    }
+
+    fn visit_matches(&self, grid: &Grid, rows: Range<usize>, mut found: impl FnMut(TemplateMatch)) {
+        let width = grid.iter().map(|cells| cells.len()).max().unwrap_or(0);
+        for row in rows {
+            for column in 0..width {
+                for (template_index, template) in self.templates.iter().enumerate() {
+                    for (orientation, stencil) in template.orientations.iter().enumerate() {
+                        if stencil.matches_at(grid, row, column) {
+                            found(TemplateMatch {
+                                template: template_index,
+                                orientation,
+                                row,
+                                column,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One template's count for an input as a JSON record.
+pub fn get_template_count_json(input: &str, template: &Template, count: usize) -> String {
+    return json!({
+        "input": input,
+        "template": template.name,
+        "count": count,
+    })
+    .to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::grid::get_grid_from_raw_data;
+
+    #[test]
+    fn orientations_are_distinct() {
+        let x_mas = Template::new("x-mas", "M.S\n.A.\nM.S", true, false).unwrap();
+        assert_eq!(x_mas.get_orientation_count(), 4);
+        let plus = Template::new("plus", " A\nAAA\n A", true, true).unwrap();
+        assert_eq!(plus.get_orientation_count(), 1);
+        let ell = Template::new("ell", "A\nA\nAB", true, true).unwrap();
+        assert_eq!(ell.get_orientation_count(), 8);
+        let ell = Template::new("ell", "A\nA\nAB", false, true).unwrap();
+        assert_eq!(ell.get_orientation_count(), 3);
+        assert!(Template::new("empty", "  \n ", true, true).is_err());
+    }
+
+    #[test]
+    fn rotations_cover_every_way_round() {
+        let x_mas = Template::new("x-mas", "M.S\n.A.\nM.S", true, false).unwrap();
+        let search = TemplateSearch::new(vec![x_mas]);
+        for shape in [
+            "M.S\n.A.\nM.S",
+            "M.M\n.A.\nS.S",
+            "S.M\n.A.\nS.M",
+            "S.S\n.A.\nM.M",
+        ] {
+            let grid = get_grid_from_raw_data(&shape.replace('.', "X"));
+            assert_eq!(search.count_matches(&grid), vec![1], "{}", shape);
+        }
+        let grid = get_grid_from_raw_data("MXM\nXAX\nSXM");
+        assert_eq!(search.count_matches(&grid), vec![0]);
+    }
+
+    #[test]
+    fn gaps_and_wildcards() {
+        let grid = get_grid_from_raw_data("ABA\nBBB\nABA\nB");
+        let plus = Template::new("plus", " B\nBBB\n B", false, false).unwrap();
+        let corners = Template::new("corners", "A.A\n...\nA.A", false, false).unwrap();
+        let column = Template::new("column", "B\nB", false, false).unwrap();
+        let search = TemplateSearch::new(vec![plus, corners, column]);
+        assert_eq!(search.count_matches(&grid), vec![1, 1, 2]);
+        let matches = search.find_matches(&grid);
+        assert_eq!(
+            matches[0],
+            TemplateMatch {
+                template: 0,
+                orientation: 0,
+                row: 0,
+                column: 0
+            }
+        );
+        assert_eq!(matches[1].template, 1);
+        assert_eq!(
+            search.get_cells(&matches[0]),
+            vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn reads_templates_from_toml() {
+        let search = TemplateSearch::from_toml(
+            "[[template]]\nname = \"x-mas\"\nshape = \"\"\"\nM.S\n.A.\nM.S\n\"\"\"\nrotations = true\n",
+        )
+        .unwrap();
+        assert_eq!(search.get_templates()[0].name, "x-mas");
+        assert_eq!(search.get_templates()[0].get_orientation_count(), 4);
+        assert!(TemplateSearch::from_toml("[[template]]\nname = \"x\"\n").is_err());
+        assert!(TemplateSearch::from_toml("[[template]]\nname = \"x\"\nshape = \" \"\n").is_err());
+    }
+}
@@ -0,0 +1,411 @@
+use std::{fmt, ops::Range, thread};
+
+use aoc_common::grid::Grid;
+use serde_json::json;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// Which way a word reads from its first letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+    LeftUp,
+    RightUp,
+    LeftDown,
+    RightDown,
+}
+
+impl Direction {
+    /// How far one step in this direction moves, in rows and then columns.
+    pub fn get_step(&self) -> (isize, isize) {
+        return match self {
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::LeftUp => (-1, -1),
+            Direction::RightUp => (-1, 1),
+            Direction::LeftDown => (1, -1),
+            Direction::RightDown => (1, 1),
+        };
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "left"),
+            Direction::Right => write!(f, "right"),
+            Direction::Up => write!(f, "up"),
+            Direction::Down => write!(f, "down"),
+            Direction::LeftUp => write!(f, "left-up"),
+            Direction::RightUp => write!(f, "right-up"),
+            Direction::LeftDown => write!(f, "left-down"),
+            Direction::RightDown => write!(f, "right-down"),
+        }
+    }
+}
+
+/// A word found in a grid, starting at `row` and `column` and reading in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The word's index in the dictionary the search was built from.
+    pub word: usize,
+    pub row: usize,
+    pub column: usize,
+    pub direction: Direction,
+}
+
+impl Match {
+    /// `word` is the matched word, looked up from the dictionary.
+    pub fn to_text(&self, word: &str) -> String {
+        return format!(
+            "{} at row {}, column {} reading {}",
+            word, self.row, self.column, self.direction
+        );
+    }
+
+    pub fn to_json(&self, input: &str, word: &str) -> String {
+        return json!({
+            "input": input,
+            "word": word,
+            "row": self.row,
+            "column": self.column,
+            "direction": self.direction.to_string(),
+        })
+        .to_string();
+    }
+}
+
+#[derive(Default)]
+struct TrieNode {
+    /// The next letters and their nodes; few enough in practice that a list beats a map.
+    children: Vec<(char, usize)>,
+    /// The index of the word ending at this node, if any.
+    word: Option<usize>,
+}
+
+/// Grids are only split between workers in bands of at least this many rows, as smaller ones
+/// aren't worth a thread.
+const MIN_ROWS_PER_WORKER: usize = 32;
+
+/// How many threads to search with by default: one per available core.
+pub fn get_default_workers() -> usize {
+    return thread::available_parallelism().map_or(1, |workers| workers.get());
+}
+
+/// Finds every word of a dictionary in a grid, reading in any of the eight directions.
+///
+/// The words are kept in a trie, so each cell is only walked from once per direction however
+/// many words there are. Single letter words read the same every way, so they're only found
+/// reading right. Large grids are split into bands of rows searched on separate threads.
+pub struct WordSearch {
+    words: Vec<String>,
+    nodes: Vec<TrieNode>,
+    workers: usize,
+}
+
+impl WordSearch {
+    /// Repeated words are only searched for once, as the first of them.
+    pub fn new(words: &Vec<String>) -> Result<WordSearch, String> {
+        let mut nodes = vec![TrieNode::default()];
+        for (index, word) in words.iter().enumerate() {
+            if word.is_empty() {
+                return Err("words to search for must not be empty".to_string());
+            }
+            let mut node = 0;
+            for letter in word.chars() {
+                node = match get_child(&nodes[node], letter) {
+                    Some(child) => child,
+                    None => {
+                        nodes.push(TrieNode::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.push((letter, child));
+                        child
+                    }
+                };
+            }
+            nodes[node].word.get_or_insert(index);
+        }
+        return Ok(WordSearch {
+            words: words.clone(),
+            nodes,
+            workers: get_default_workers(),
+        });
+    }
+
+    /// Searches on at most `workers` threads; 0 is taken as 1.
+    pub fn with_workers(mut self, workers: usize) -> WordSearch {
+        self.workers = workers.max(1);
+        return self;
+    }
+
+    pub fn get_words(&self) -> &Vec<String> {
+        return &self.words;
+    }
+
+    /// Every match in the grid, ordered by start row, then column, then direction.
+    pub fn find_matches(&self, grid: &Grid) -> Vec<Match> {
+        let bands = scan_row_bands(grid, self.workers, |rows| {
+            let mut matches = Vec::new();
+            self.visit_matches(grid, rows, |found| matches.push(found));
+            matches
+        });
+        return bands.concat();
+    }
+
+    pub fn count_matches(&self, grid: &Grid) -> usize {
+        let bands = scan_row_bands(grid, self.workers, |rows| {
+            let mut count = 0;
+            self.visit_matches(grid, rows, |_| count += 1);
+            count
+        });
+        return bands.iter().sum();
+    }
+
+    /// The grid cells a match covers, as rows and columns, from its first letter to its last.
+    pub fn get_cells(&self, found: &Match) -> Vec<(usize, usize)> {
+        let (row_step, column_step) = found.direction.get_step();
+        let length = self.words[found.word].chars().count() as isize;
+        return (0..length)
+            .map(|i| {
+                (
+                    (found.row as isize + i * row_step) as usize,
+                    (found.column as isize + i * column_step) as usize,
+                )
+            })
+            .collect();
+    }
+
+    /// Calls `found` with every match starting in `rows`, in order.
+    fn visit_matches(&self, grid: &Grid, rows: Range<usize>, mut found: impl FnMut(Match)) {
+        for row in rows {
+            for column in 0..grid[row].len() {
+                for direction in Direction::iter() {
+                    self.walk(grid, row, column, direction, |word, length| {
+                        if length > 1 || direction == Direction::Right {
+                            found(Match {
+                                word,
+                                row,
+                                column,
+                                direction,
+                            });
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    /// Follows the trie along the grid from a cell, calling `found` with the index and length of
+    /// every word that ends on the way.
+    fn walk(
+        &self,
+        grid: &Grid,
+        row: usize,
+        column: usize,
+        direction: Direction,
+        mut found: impl FnMut(usize, usize),
+    ) {
+        let (row_step, column_step) = direction.get_step();
+        let mut node = 0;
+        let mut length = 0;
+        let (mut x, mut y) = (row as isize, column as isize);
+        while let Some(letter) = get_cell(grid, x, y) {
+            let Some(child) = get_child(&self.nodes[node], letter) else {
+                return;
+            };
+            node = child;
+            length += 1;
+            if let Some(word) = self.nodes[node].word {
+                found(word, length);
+            }
+            x += row_step;
+            y += column_step;
+        }
+    }
+}
+
+/// Runs `scan` over bands of consecutive rows, one per worker, returning each band's result in
+/// row order.
+pub(crate) fn scan_row_bands<T: Send>(
+    grid: &Grid,
+    workers: usize,
+    scan: impl Fn(Range<usize>) -> T + Sync,
+) -> Vec<T> {
+    let workers = workers.min(grid.len().div_ceil(MIN_ROWS_PER_WORKER));
+    if workers <= 1 {
+        return vec![scan(0..grid.len())];
+    }
+    let band_size = grid.len().div_ceil(workers);
+    let scan = &scan;
+    return thread::scope(|s| {
+        let handles: Vec<_> = (0..grid.len())
+            .step_by(band_size)
+            .map(|start| s.spawn(move || scan(start..grid.len().min(start + band_size))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("search worker panicked"))
+            .collect()
+    });
+}
+
+fn get_child(node: &TrieNode, letter: char) -> Option<usize> {
+    return node
+        .children
+        .iter()
+        .find(|(next, _)| *next == letter)
+        .map(|(_, child)| *child);
+}
+
+fn get_cell(grid: &Grid, row: isize, column: isize) -> Option<char> {
+    if row < 0 || column < 0 {
+        return None;
+    }
+    return grid.get(row as usize)?.get(column as usize).copied();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::grid::get_grid_from_raw_data;
+
+    fn get_words(words: &[&str]) -> Vec<String> {
+        return words.iter().map(|word| word.to_string()).collect();
+    }
+
+    #[test]
+    fn finds_words_in_every_direction() {
+        let grid = get_grid_from_raw_data(
+            "S..S..S\n.A.A.A.\n..MMM..\nSAMXMAS\n..MMM..\n.A.A.A.\nS..S..S\n",
+        );
+        let search = WordSearch::new(&get_words(&["XMAS"])).unwrap();
+        let matches = search.find_matches(&grid);
+        assert_eq!(matches.len(), 8);
+        for direction in Direction::iter() {
+            assert!(matches.contains(&Match {
+                word: 0,
+                row: 3,
+                column: 3,
+                direction
+            }));
+        }
+    }
+
+    #[test]
+    fn finds_every_word_of_a_dictionary() {
+        let grid = get_grid_from_raw_data("CATS\nAXXX\nRXXX\n");
+        let search = WordSearch::new(&get_words(&["CAT", "CATS", "CAR", "DOG", "CAT"])).unwrap();
+        let matches = search.find_matches(&grid);
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    word: 0,
+                    row: 0,
+                    column: 0,
+                    direction: Direction::Right
+                },
+                Match {
+                    word: 1,
+                    row: 0,
+                    column: 0,
+                    direction: Direction::Right
+                },
+                Match {
+                    word: 2,
+                    row: 0,
+                    column: 0,
+                    direction: Direction::Down
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_short_words_and_ragged_grids() {
+        let grid = get_grid_from_raw_data("ABA\nB\nAB\n");
+        let search = WordSearch::new(&get_words(&["A", "ABA"])).unwrap();
+        let matches = search.find_matches(&grid);
+        let singles = matches.iter().filter(|m| m.word == 0).count();
+        let words: Vec<(usize, usize, Direction)> = matches
+            .iter()
+            .filter(|m| m.word == 1)
+            .map(|m| (m.row, m.column, m.direction))
+            .collect();
+        assert_eq!(singles, 3);
+        assert_eq!(
+            words,
+            vec![
+                (0, 0, Direction::Right),
+                (0, 0, Direction::Down),
+                (0, 2, Direction::Left),
+                (2, 0, Direction::Up)
+            ]
+        );
+        assert!(WordSearch::new(&get_words(&[""])).is_err());
+    }
+
+    #[test]
+    fn workers_agree_with_a_single_thread() {
+        let rows: Vec<String> = (0..100)
+            .map(|row| {
+                (0..40)
+                    .map(|column| ['X', 'M', 'A', 'S'][(row * 7 + column * 3 + row * column) % 4])
+                    .collect()
+            })
+            .collect();
+        let grid = get_grid_from_raw_data(&rows.join("\n"));
+        let words = get_words(&["XMAS", "SAM", "AX"]);
+        let single = WordSearch::new(&words).unwrap().with_workers(1);
+        let expected = single.find_matches(&grid);
+        assert!(!expected.is_empty());
+        for workers in [0, 2, 3, 7, 64] {
+            let search = WordSearch::new(&words).unwrap().with_workers(workers);
+            assert_eq!(search.find_matches(&grid), expected, "{} workers", workers);
+            assert_eq!(
+                search.count_matches(&grid),
+                expected.len(),
+                "{} workers",
+                workers
+            );
+        }
+    }
+
+    #[test]
+    fn matches_cover_their_letters() {
+        let search = WordSearch::new(&get_words(&["XMAS"])).unwrap();
+        let found = Match {
+            word: 0,
+            row: 3,
+            column: 3,
+            direction: Direction::LeftUp,
+        };
+        assert_eq!(
+            search.get_cells(&found),
+            vec![(3, 3), (2, 2), (1, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn formats_matches() {
+        let found = Match {
+            word: 0,
+            row: 2,
+            column: 5,
+            direction: Direction::LeftUp,
+        };
+        assert_eq!(
+            found.to_text("XMAS"),
+            "XMAS at row 2, column 5 reading left-up"
+        );
+        assert_eq!(
+            found.to_json("day4.txt", "XMAS"),
+            r#"{"column":5,"direction":"left-up","input":"day4.txt","row":2,"word":"XMAS"}"#
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use aoc_common::{
+    blocks::get_blocks_from_raw_data,
+    error::{parse_token, ParseError},
+    solver::{Answer, Solver},
+};
+use log::{debug, info, trace};
+use std::thread;
+
+pub struct PrintQueue {
+    ordering_rules: Vec<Vec<i32>>,
+    updates: Vec<Vec<i32>>,
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = PrintQueue;
+
+    const DAY: u32 = 5;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let blocks = get_blocks_from_raw_data(raw_data);
+        if blocks.len() < 2 {
+            return Err(ParseError::at_end(
+                raw_data,
+                "expected ordering rules, a blank line, then page updates",
+            ));
+        }
+        let ordering_rules: Vec<Vec<i32>> =
+            get_ordering_rules_from_ordering_rule_pairs(raw_data, &blocks[0])?;
+        let updates = get_updates_as_i32_vec(raw_data, &blocks[1])?;
+        return Ok(PrintQueue {
+            ordering_rules,
+            updates,
+        });
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        debug!("PROCESSING VALID UPDATES...");
+        let valid_updates = get_valid_updates(&input.updates, &input.ordering_rules);
+        let middle_pages = get_middle_page_numbers_from_valid_updates(&valid_updates);
+        let sum = sum_middle_pages(middle_pages);
+        return Some(sum.to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        debug!("PROCESSING INVALID UPDATES...");
+        let invalid_updates = get_invalid_updates(&input.updates, &input.ordering_rules);
+        let sorted_updates = sort_invalid_updates(&invalid_updates, &input.ordering_rules);
+        let sorted_middle_pages = get_middle_page_numbers_from_valid_updates(&sorted_updates);
+        let sum = sum_middle_pages(sorted_middle_pages);
+        return Some(sum.to_string());
+    }
+}
+
+fn sum_middle_pages(pages: Vec<i32>) -> i32 {
+    let mut sum = 0;
+    for page in pages {
+        sum += page;
+    }
+    info!("Middle Pages Sum: {}", sum);
+    return sum;
+}
+
+fn get_ordering_rules_from_ordering_rule_pairs(
+    raw_data: &str,
+    ordering_rule_pairs: &Vec<&str>,
+) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut ordering_rules = Vec::new();
+    for ordering_pair_str in ordering_rule_pairs {
+        let ordering_pair: Vec<i32> = ordering_pair_str
+            .split('|')
+            .map(|op| parse_token::<i32>(raw_data, op))
+            .collect::<Result<_, _>>()?;
+        if ordering_pair.len() != 2 {
+            return Err(ParseError::at(
+                raw_data,
+                ordering_pair_str,
+                "expected an ordering rule like '47|53'",
+            ));
+        }
+        ordering_rules.push(ordering_pair);
+    }
+    return Ok(ordering_rules);
+}
+
+fn get_updates_as_i32_vec(
+    raw_data: &str,
+    update_strings: &Vec<&str>,
+) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut updates: Vec<Vec<i32>> = Vec::new();
+    for update_string in update_strings {
+        let numbers: Vec<i32> = update_string
+            .split(',')
+            .map(|us| parse_token::<i32>(raw_data, us))
+            .collect::<Result<_, _>>()?;
+
+        updates.push(numbers);
+    }
+    debug!("{:?}", updates);
+
+    return Ok(updates);
+}
+
+fn get_valid_updates(updates: &Vec<Vec<i32>>, sorting_rules: &Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    let mut valid_updates: Vec<Vec<i32>> = Vec::new();
+    for update in updates {
+        if is_valid_update(update, sorting_rules) {
+            valid_updates.push(update.clone());
+        }
+    }
+
+    debug!("Valid Updates\n{:?}", valid_updates);
+
+    return valid_updates;
+}
+fn get_invalid_updates(updates: &Vec<Vec<i32>>, sorting_rules: &Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    let mut invalid_updates: Vec<Vec<i32>> = Vec::new();
+    for update in updates {
+        if !is_valid_update(update, sorting_rules) {
+            invalid_updates.push(update.clone());
+        }
+    }
+
+    debug!("Invalid Updates\n{:?}", invalid_updates);
+
+    return invalid_updates;
+}
+
+fn is_valid_update(update: &Vec<i32>, sorting_rules: &Vec<Vec<i32>>) -> bool {
+    let mut is_update_good = false;
+    for rule in sorting_rules {
+        let left_pos = update.iter().position(|&page| page == rule[0]);
+        let right_pos = update.iter().position(|&page| page == rule[1]);
+        if left_pos.is_none() || right_pos.is_none() {
+            continue;
+        }
+        //println!("Rule: {:?}", rule);
+        if left_pos > right_pos {
+            is_update_good = false;
+            break;
+        }
+        is_update_good = true;
+    }
+    return is_update_good;
+}
+
+fn sort_invalid_updates(
+    invalid_updates: &Vec<Vec<i32>>,
+    sorting_rules: &Vec<Vec<i32>>,
+) -> Vec<Vec<i32>> {
+    // Joining the threads in the order they were spawned keeps the updates in input order.
+    let updates: Vec<Vec<i32>> = thread::scope(|s| {
+        let handles: Vec<_> = invalid_updates
+            .iter()
+            .map(|update| s.spawn(move || sort_update(update, sorting_rules)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("sorting thread panicked"))
+            .collect()
+    });
+
+    get_valid_updates(&updates, sorting_rules);
+    return updates;
+}
+
+fn sort_update(update: &Vec<i32>, sorting_rules: &Vec<Vec<i32>>) -> Vec<i32> {
+    let mut sorted_update = update.clone();
+    let mut is_valid = is_valid_update(&sorted_update, sorting_rules);
+    while !is_valid {
+        for rule in sorting_rules {
+            let left_pos = sorted_update.iter().position(|&page| page == rule[0]);
+            let right_pos = sorted_update.iter().position(|&page| page == rule[1]);
+            if left_pos.is_none() || right_pos.is_none() {
+                continue;
+            }
+            //println!("Rule: {:?}", rule);
+            if left_pos > right_pos {
+                sorted_update.swap(left_pos.unwrap(), right_pos.unwrap());
+            }
+            is_valid = is_valid_update(&sorted_update, sorting_rules);
+        }
+    }
+    trace!("!sorted: {:?}", update);
+    trace!(" sorted: {:?}", sorted_update);
+    return sorted_update;
+}
+
+fn get_middle_page_numbers_from_valid_updates(updates: &Vec<Vec<i32>>) -> Vec<i32> {
+    let mut middle_pages: Vec<i32> = Vec::new();
+    for update in updates {
+        middle_pages.push(update[update.len() / 2])
+    }
+    debug!("Middle Pages: {:?}", middle_pages);
+    return middle_pages;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn is_valid_update_matches_sample() {
+        let queue = Day5.parse(TEST_CASE).unwrap();
+        let valid: Vec<bool> = queue
+            .updates
+            .iter()
+            .map(|update| is_valid_update(update, &queue.ordering_rules))
+            .collect();
+        assert_eq!(valid, vec![true, true, true, false, false, false]);
+    }
+
+    #[test]
+    fn sort_invalid_updates_matches_sample() {
+        let queue = Day5.parse(TEST_CASE).unwrap();
+        let invalid_updates = get_invalid_updates(&queue.updates, &queue.ordering_rules);
+        assert_eq!(
+            sort_invalid_updates(&invalid_updates, &queue.ordering_rules),
+            vec![
+                vec![97, 75, 47, 61, 53],
+                vec![61, 29, 13],
+                vec![97, 75, 47, 29, 13]
+            ]
+        );
+    }
+
+    #[test]
+    fn sample_answers() {
+        let queue = Day5.parse(TEST_CASE).unwrap();
+        assert_eq!(Day5.part_one(&queue), Some("143".to_string()));
+        assert_eq!(Day5.part_two(&queue), Some("123".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_missing_updates() {
+        assert!(Day5.parse("47|53\n97|13\n").is_err());
+    }
+}
@@ -0,0 +1,284 @@
+use log::{debug, info, trace, warn};
+use std::{
+    collections::HashSet,
+    fmt::format,
+    thread::{self},
+};
+use strum::EnumCount;
+use strum_macros::{EnumCount as EnumCountMacro, EnumIter};
+
+use aoc_common::{
+    error::ParseError,
+    grid::{get_grid_from_raw_data, Grid},
+    solver::{Answer, Solver},
+};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+struct DirectionalCoordinate {
+    coordinate: Coordinate,
+    direction: Direction,
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = Grid;
+
+    const DAY: u32 = 6;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        let map = get_grid_from_raw_data(raw_data);
+        if get_starting_position(&map).is_none() {
+            return Err(ParseError::at_end(
+                raw_data,
+                "the map has no starting position '^'",
+            ));
+        }
+        return Ok(map);
+    }
+
+    /// `None` if the guard never leaves the map.
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let unique_coordinates = traverse_map_get_unique_coordinates(input)?;
+        info!("Unique Coordinate Count: {}", unique_coordinates.len());
+        return Some(unique_coordinates.len().to_string());
+    }
+
+    /// `None` if the guard never leaves the map.
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let unique_coordinates = traverse_map_get_unique_coordinates(input)?;
+        let obstacle_count = get_obstacle_count_that_cause_loop(input, &unique_coordinates);
+        info!("SUCCESSFULLY PLACED {} OBSTACLES", obstacle_count);
+        return Some(obstacle_count.to_string());
+    }
+}
+
+/// Tries an obstacle on every cell of the guard's original path, each on its own thread, and
+/// counts those that trap the guard in a loop.
+fn get_obstacle_count_that_cause_loop(
+    map: &Vec<Vec<char>>,
+    original_unique_coordinates: &HashSet<Coordinate>,
+) -> usize {
+    return thread::scope(|s| {
+        let mut handles = Vec::new();
+        for x in 0..map.len() {
+            for y in 0..map[x].len() {
+                let mut local_map = map.clone();
+                if local_map[x][y] == '^'
+                    || !original_unique_coordinates.contains(&Coordinate { x, y })
+                {
+                    continue;
+                }
+                local_map[x][y] = '#';
+                let handle = thread::Builder::new()
+                    .name(format(format_args!("{:?}", Coordinate { x, y })))
+                    .spawn_scoped(s, move || {
+                        trace!(
+                            "Spawned Thread: Placed obstical at {:?}",
+                            thread::current().name().unwrap()
+                        );
+
+                        let option = traverse_map_get_unique_coordinates(&local_map);
+                        if option.is_none() {
+                            trace!("ADDED AN OBSTACLE TO THE COUNT")
+                        }
+                        option.is_none()
+                    })
+                    .expect("failed to spawn an obstacle thread");
+                handles.push(handle);
+            }
+        }
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("obstacle thread panicked"))
+            .filter(|causes_loop| *causes_loop)
+            .count()
+    });
+}
+
+fn traverse_map_get_unique_coordinates(map: &Vec<Vec<char>>) -> Option<HashSet<Coordinate>> {
+    let mut unique_coordinates: HashSet<Coordinate> = HashSet::new();
+
+    let current_pos = get_starting_position(map);
+    if current_pos.is_none() {
+        warn!("Something went weird with your input file, there was no starting position...")
+    }
+    let mut current_pos = current_pos.unwrap();
+    let mut current_direction = Direction::Up;
+    let mut can_exit = is_able_to_exit(map, &current_pos, &current_direction);
+
+    let mut loop_corners: Vec<DirectionalCoordinate> = Vec::new();
+
+    debug!("Starting Position: {:?}", current_pos);
+
+    while !can_exit {
+        let have_we_been_here_before = unique_coordinates.contains(&current_pos);
+        unique_coordinates.insert(current_pos);
+        can_exit = is_able_to_exit(map, &current_pos, &current_direction);
+        if can_exit {
+            break;
+        }
+        let can_proceed = !is_facing_obsitcle(map, &current_pos, &current_direction);
+        // println!("Current Position: {:?}, Can Proceed? {}",current_pos, can_proceed);
+        if !can_proceed {
+            current_direction =
+                Direction::from_usize(((current_direction as usize) + 1) % Direction::COUNT);
+            if have_we_been_here_before {
+                loop_corners.push(DirectionalCoordinate {
+                    coordinate: current_pos,
+                    direction: current_direction,
+                });
+                //println!("Loop Corners({}): {:?}", loop_corners.len(), loop_corners);
+            }
+            if loop_corners.len().is_multiple_of(2) && loop_corners.len() > 4 {
+                for i in 0..=3 {
+                    if loop_corners[i] == loop_corners[i + (loop_corners.len() / 2) - 1] {
+                        continue;
+                    }
+                    return None;
+                }
+            }
+            continue;
+        }
+
+        current_pos.move_direction(&current_direction);
+    }
+
+    debug!("SUCCESSFULLY EXITED MAP");
+
+    return Some(unique_coordinates);
+}
+
+fn is_able_to_exit(
+    map: &Vec<Vec<char>>,
+    current_pos: &Coordinate,
+    current_direction: &Direction,
+) -> bool {
+    let adjacent_edges = get_immediately_adjacent_edges(map, current_pos);
+    //println!("Adjacent Edges: {:?}", adjacent_edges);
+    if adjacent_edges.is_empty() {
+        return false;
+    }
+    for edge in adjacent_edges {
+        if edge == *current_direction {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn get_immediately_adjacent_edges(
+    map: &Vec<Vec<char>>,
+    current_pos: &Coordinate,
+) -> Vec<Direction> {
+    let mut directions = Vec::new();
+    if current_pos.x == 0 {
+        directions.push(Direction::Up);
+    }
+    if current_pos.x == map.len() - 1 {
+        directions.push(Direction::Down);
+    }
+    if current_pos.y == 0 {
+        directions.push(Direction::Left);
+    }
+    if current_pos.y == map[0].len() - 1 {
+        directions.push(Direction::Right);
+    }
+
+    return directions;
+}
+
+fn is_facing_obsitcle(
+    map: &Vec<Vec<char>>,
+    current_pos: &Coordinate,
+    direction: &Direction,
+) -> bool {
+    let mut coordinate_to_check = *current_pos;
+    coordinate_to_check.move_direction(direction);
+    if map[coordinate_to_check.x][coordinate_to_check.y] == '#' {
+        return true;
+    }
+    return false;
+}
+
+fn get_starting_position(map: &Vec<Vec<char>>) -> Option<Coordinate> {
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            if map[x][y] == '^' {
+                return Some(Coordinate { x, y });
+            }
+        }
+    }
+    return None;
+}
+
+#[repr(usize)]
+#[derive(Debug, EnumCountMacro, EnumIter, Copy, Clone, Eq, PartialEq, Hash)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn from_usize(value: usize) -> Direction {
+        match value {
+            0 => Direction::Up,
+            1 => Direction::Right,
+            2 => Direction::Down,
+            3 => Direction::Left,
+            _ => panic!("Unknown value: {}", value),
+        }
+    }
+}
+
+impl Coordinate {
+    fn move_direction(&mut self, direction: &Direction) {
+        match direction {
+            Direction::Up => self.x -= 1,
+            Direction::Right => self.y += 1,
+            Direction::Down => self.x += 1,
+            Direction::Left => self.y -= 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn starting_position_matches_sample() {
+        let map = Day6.parse(TEST_CASE).unwrap();
+        assert_eq!(get_starting_position(&map), Some(Coordinate { x: 6, y: 4 }));
+    }
+
+    #[test]
+    fn sample_answers() {
+        let map = Day6.parse(TEST_CASE).unwrap();
+        assert_eq!(Day6.part_one(&map), Some("41".to_string()));
+        assert_eq!(Day6.part_two(&map), Some("6".to_string()));
+    }
+
+    #[test]
+    fn part_two_can_run_repeatedly() {
+        let map = Day6.parse(TEST_CASE).unwrap();
+        for _ in 0..3 {
+            assert_eq!(Day6.part_two(&map), Some("6".to_string()));
+        }
+    }
+
+    #[test]
+    fn parse_rejects_map_without_guard() {
+        assert!(Day6.parse("..#\n...\n").is_err());
+    }
+}
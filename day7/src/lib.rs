@@ -0,0 +1,186 @@
+use aoc_common::{
+    error::{parse_token, ParseError},
+    solver::{Answer, Solver},
+};
+use log::info;
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    total: i64,
+    operands: Vec<i64>,
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input = Vec<Operation>;
+
+    const DAY: u32 = 7;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return get_operations_from_raw_data(raw_data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let allowed_operators = vec!['+', '*'];
+        let valid_operations = get_valid_operations(input, &allowed_operators);
+        let calibration_total = get_total_calibaration_result(&valid_operations);
+        info!("Calibration Total: {}", calibration_total);
+        return Some(calibration_total.to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let allowed_operators = vec!['+', '*', '|'];
+        let valid_operations = get_valid_operations(input, &allowed_operators);
+        let calibration_total = get_total_calibaration_result(&valid_operations);
+        info!("Calibration Total: {}", calibration_total);
+        return Some(calibration_total.to_string());
+    }
+}
+
+fn get_total_calibaration_result(operations: &Vec<Operation>) -> i64 {
+    let mut sum = 0;
+    for operation in operations {
+        sum += operation.total;
+    }
+    return sum;
+}
+fn get_valid_operations(
+    operations: &Vec<Operation>,
+    allowed_operators: &Vec<char>,
+) -> Vec<Operation> {
+    return operations
+        .iter()
+        .filter(|operation| is_valid_operation(operation, allowed_operators))
+        .cloned()
+        .collect();
+}
+
+fn is_valid_operation(operation: &Operation, allowed_operators: &Vec<char>) -> bool {
+    let mut expressions: Vec<i64> = Vec::new();
+
+    if !operation.operands.is_empty() {
+        recursively_get_expressions(
+            &operation.operands[1..].to_vec(),
+            operation.operands[0],
+            &mut expressions,
+            allowed_operators,
+        );
+    }
+    if expressions.contains(&operation.total) {
+        return true;
+    }
+    return false;
+}
+
+fn recursively_get_expressions(
+    operands: &Vec<i64>,
+    current_result: i64,
+    combinations: &mut Vec<i64>,
+    allowed_operators: &Vec<char>,
+) {
+    if operands.is_empty() {
+        combinations.push(current_result);
+        return;
+    }
+
+    let next_operand = &operands[0];
+    let remaining = &operands[1..].to_vec();
+    for operator in allowed_operators {
+        if *operator == '+' {
+            recursively_get_expressions(
+                remaining,
+                next_operand + current_result,
+                combinations,
+                allowed_operators,
+            );
+        }
+        if *operator == '*' {
+            recursively_get_expressions(
+                remaining,
+                next_operand * current_result,
+                combinations,
+                allowed_operators,
+            );
+        }
+        if *operator == '|' {
+            let concat = format!("{}{}", current_result, next_operand).parse::<i64>();
+            recursively_get_expressions(
+                remaining,
+                concat.unwrap(),
+                combinations,
+                allowed_operators,
+            );
+        }
+    }
+}
+
+fn get_operations_from_raw_data(data: &str) -> Result<Vec<Operation>, ParseError> {
+    let mut operations = Vec::new();
+    for line in data.lines() {
+        let partitions: Vec<&str> = line.split(": ").collect();
+        if partitions.len() != 2 {
+            return Err(ParseError::at(
+                data,
+                line,
+                "expected an equation like '3267: 81 40 27'",
+            ));
+        }
+        let total = parse_token::<i64>(data, partitions[0])?;
+        let operands: Vec<i64> = partitions[1]
+            .split(" ")
+            .map(|op| parse_token::<i64>(data, op))
+            .collect::<Result<_, _>>()?;
+        operations.push(Operation { total, operands })
+    }
+    // println!("Operations:\n{:?}", operations);
+    return Ok(operations);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn recursively_get_expressions_combines_every_operator() {
+        let mut expressions = Vec::new();
+        recursively_get_expressions(&vec![19], 10, &mut expressions, &vec!['+']);
+        assert_eq!(expressions, vec![29]);
+
+        let mut expressions = Vec::new();
+        recursively_get_expressions(&vec![19], 10, &mut expressions, &vec!['*']);
+        assert_eq!(expressions, vec![190]);
+
+        let mut expressions = Vec::new();
+        recursively_get_expressions(&vec![15], 6, &mut expressions, &vec!['+', '*', '|']);
+        assert_eq!(expressions, vec![21, 90, 615]);
+
+        let mut expressions = Vec::new();
+        recursively_get_expressions(&vec![3, 4], 2, &mut expressions, &vec!['+', '*']);
+        assert_eq!(expressions, vec![9, 20, 10, 24]);
+    }
+
+    #[test]
+    fn is_valid_operation_matches_sample() {
+        let operations = Day7.parse(TEST_CASE).unwrap();
+        let valid: Vec<i64> = get_valid_operations(&operations, &vec!['+', '*'])
+            .iter()
+            .map(|operation| operation.total)
+            .collect();
+        assert_eq!(valid, vec![190, 3267, 292]);
+    }
+
+    #[test]
+    fn sample_answers() {
+        let operations = Day7.parse(TEST_CASE).unwrap();
+        assert_eq!(Day7.part_one(&operations), Some("3749".to_string()));
+        assert_eq!(Day7.part_two(&operations), Some("11387".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_missing_separator() {
+        assert!(Day7.parse("190 10 19\n").is_err());
+    }
+}
@@ -0,0 +1,331 @@
+use log::{debug, info, trace};
+use std::collections::HashSet;
+
+use aoc_common::{
+    error::ParseError,
+    grid::{check_rectangular, get_grid_from_raw_data, Grid},
+    solver::{Answer, Solver},
+};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+struct Coordinate {
+    x: isize,
+    y: isize,
+}
+
+type Map = Grid;
+type Coordinates = Vec<Coordinate>;
+type Frequency = char;
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = Map;
+
+    const DAY: u32 = 8;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        check_rectangular(raw_data)?;
+        return Ok(get_grid_from_raw_data(raw_data));
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let unique_antinodes = get_unique_antinodes(input);
+        info!("Unique Antinode Count: {}", unique_antinodes.len());
+        return Some(unique_antinodes.len().to_string());
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let unique_antinodes = get_all_unique_antinodes(input);
+        info!("Unique Antinode Count: {}", unique_antinodes.len());
+        return Some(unique_antinodes.len().to_string());
+    }
+}
+
+fn get_unique_antinodes(map: &Vec<Vec<char>>) -> HashSet<Coordinate> {
+    let mut unique_antinodes = HashSet::new();
+    let unique_freqs = get_all_unique_frequencies(map);
+    debug!("Unique Frequencies: {:?}", unique_freqs);
+
+    for frequency in unique_freqs {
+        let all_tower_coordinates = get_all_tower_locations_for_freq(map, frequency);
+        /*
+        debug!("All Tower Coordinates({}): {:?}",
+            frequency, all_tower_coordinates
+        );
+        */
+        let antinodes = get_antinodes_for_freq(&all_tower_coordinates);
+        let antinodes: Coordinates = antinodes
+            .iter()
+            .filter(|ac| {
+                ac.y >= 0 && ac.x >= 0 && ac.x < map.len() as isize && ac.y < map[0].len() as isize
+            })
+            .copied()
+            .collect();
+        debug!("Antinode Count: {}", antinodes.len());
+        //trace!("{}", render_coordinates(&map, &antinodes));
+
+        for antinode in antinodes {
+            unique_antinodes.insert(antinode);
+        }
+    }
+
+    debug!(
+        "Antinodes:\n{}",
+        render_coordinates(map, &unique_antinodes.clone().into_iter().collect())
+    );
+    return unique_antinodes;
+}
+
+fn get_antinodes_for_freq(tower_coordinates: &Coordinates) -> Coordinates {
+    let mut antinode_coordinates = Vec::new();
+    for i in 0..tower_coordinates.len() {
+        for j in i + 1..tower_coordinates.len() {
+            let delta_x = tower_coordinates[i].x - tower_coordinates[j].x;
+            let delta_y = tower_coordinates[i].y - tower_coordinates[j].y;
+            trace!("Delta X: {} | Delta Y: {}", delta_x, delta_y);
+
+            let point1_x;
+            let point1_y;
+
+            let point2_x;
+            let point2_y;
+
+            if delta_x > 0 {
+                //point i is further from origin than point j so add abs to it's x
+                point1_x = tower_coordinates[i].x + delta_x.abs();
+                point2_x = tower_coordinates[j].x - delta_x.abs();
+            } else {
+                point1_x = tower_coordinates[i].x - delta_x.abs();
+                point2_x = tower_coordinates[j].x + delta_x.abs();
+            }
+            if delta_y > 0 {
+                //point i is further from origin than point j so  sub abs to it's y
+                point1_y = tower_coordinates[i].y + delta_y.abs();
+                point2_y = tower_coordinates[j].y - delta_y.abs();
+            } else {
+                point1_y = tower_coordinates[i].y - delta_y.abs();
+                point2_y = tower_coordinates[j].y + delta_y.abs();
+            }
+
+            antinode_coordinates.push(Coordinate {
+                x: point1_x,
+                y: point1_y,
+            });
+            antinode_coordinates.push(Coordinate {
+                x: point2_x,
+                y: point2_y,
+            });
+        }
+    }
+    // println!("Anitnode Coordinates: {:?}", antinode_coordinates);
+
+    return antinode_coordinates;
+}
+fn get_all_unique_antinodes(map: &Map) -> HashSet<Coordinate> {
+    let mut unique_antinodes = HashSet::new();
+    let unique_freqs = get_all_unique_frequencies(map);
+    debug!("Unique Frequencies: {:?}", unique_freqs);
+
+    for frequency in unique_freqs {
+        let all_tower_coordinates = get_all_tower_locations_for_freq(map, frequency);
+        /*
+        debug!("All Tower Coordinates({}): {:?}",
+            frequency, all_tower_coordinates
+        );
+        */
+        let antinodes = get_propigated_antinodes_for_freq(map, &all_tower_coordinates);
+        let antinodes: Coordinates = antinodes
+            .iter()
+            .filter(|ac| {
+                ac.y >= 0 && ac.x >= 0 && ac.x < map.len() as isize && ac.y < map[0].len() as isize
+            })
+            .copied()
+            .collect();
+        debug!("Antinode Count: {}", antinodes.len());
+        //trace!("{}", render_coordinates(&map, &antinodes));
+
+        for antinode in antinodes {
+            unique_antinodes.insert(antinode);
+        }
+    }
+
+    debug!(
+        "Antinodes:\n{}",
+        render_coordinates(map, &unique_antinodes.clone().into_iter().collect())
+    );
+    return unique_antinodes;
+}
+fn get_propigated_antinodes_for_freq(map: &Map, tower_coordinates: &Coordinates) -> Coordinates {
+    let mut antinode_coordinates = Vec::new();
+    for i in 0..tower_coordinates.len() {
+        for j in i + 1..tower_coordinates.len() {
+            let delta_x = tower_coordinates[i].x - tower_coordinates[j].x;
+            let delta_y = tower_coordinates[i].y - tower_coordinates[j].y;
+            trace!("Delta X: {} | Delta Y: {}", delta_x, delta_y);
+            let i_x;
+            let i_y;
+
+            let j_x;
+            let j_y;
+
+            if delta_x > 0 {
+                //point i is further from origin than point j so add abs to it's x
+                i_x = delta_x.abs();
+                j_x = -delta_x.abs();
+            } else {
+                i_x = -delta_x.abs();
+                j_x = delta_x.abs();
+            }
+            if delta_y > 0 {
+                //point i is further from origin than point j so  sub abs to it's y
+                i_y = delta_y.abs();
+                j_y = -delta_y.abs();
+            } else {
+                i_y = -delta_y.abs();
+                j_y = delta_y.abs();
+            }
+
+            get_all_antinodes_recursive(
+                &tower_coordinates[i],
+                &mut antinode_coordinates,
+                i_x,
+                i_y,
+                map.len() as isize,
+                map[0].len() as isize,
+            );
+            get_all_antinodes_recursive(
+                &tower_coordinates[j],
+                &mut antinode_coordinates,
+                j_x,
+                j_y,
+                map.len() as isize,
+                map[0].len() as isize,
+            );
+            get_all_antinodes_recursive(
+                &tower_coordinates[i],
+                &mut antinode_coordinates,
+                j_x,
+                j_y,
+                map.len() as isize,
+                map[0].len() as isize,
+            );
+            get_all_antinodes_recursive(
+                &tower_coordinates[j],
+                &mut antinode_coordinates,
+                i_x,
+                i_y,
+                map.len() as isize,
+                map[0].len() as isize,
+            );
+        }
+    }
+    // println!("Anitnode Coordinates: {:?}", antinode_coordinates);
+
+    return antinode_coordinates;
+}
+
+fn get_all_antinodes_recursive(
+    coordinate: &Coordinate,
+    collection: &mut Coordinates,
+    delta_x: isize,
+    delta_y: isize,
+    x_max: isize,
+    y_max: isize,
+) {
+    let new = Coordinate {
+        x: coordinate.x + delta_x,
+        y: coordinate.y + delta_y,
+    };
+    if new.x < 0 || new.y < 0 || new.x >= x_max || new.y >= y_max {
+        return;
+    }
+    trace!("New Coordinate: {:?}", new);
+    collection.push(new);
+    get_all_antinodes_recursive(&new, collection, delta_x, delta_y, x_max, y_max);
+}
+
+fn get_all_unique_frequencies(map: &Map) -> HashSet<char> {
+    let mut unique_freqs = HashSet::new();
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            if map[x][y] != '.' {
+                unique_freqs.insert(map[x][y]);
+            }
+        }
+    }
+    return unique_freqs;
+}
+
+fn get_all_tower_locations_for_freq(map: &Map, frequency: Frequency) -> Coordinates {
+    let mut all_tower_coordinates: Vec<Coordinate> = Vec::new();
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            if map[x][y] == frequency {
+                all_tower_coordinates.push(Coordinate {
+                    x: x as isize,
+                    y: y as isize,
+                });
+            }
+        }
+    }
+    return all_tower_coordinates;
+}
+
+fn render_coordinates(map: &Map, coordinates: &Coordinates) -> String {
+    let mut rendered = String::new();
+    for x in 0..map.len() {
+        for y in 0..map[x].len() {
+            if coordinates.contains(&Coordinate {
+                x: x as isize,
+                y: y as isize,
+            }) {
+                rendered.push('#');
+            } else {
+                rendered.push('.');
+            }
+        }
+        rendered.push('\n');
+    }
+    return rendered;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn unique_frequencies_match_sample() {
+        let map = Day8.parse(TEST_CASE).unwrap();
+        assert_eq!(get_all_unique_frequencies(&map), HashSet::from(['0', 'A']));
+    }
+
+    #[test]
+    fn sample_answers() {
+        let map = Day8.parse(TEST_CASE).unwrap();
+        assert_eq!(Day8.part_one(&map), Some("14".to_string()));
+        assert_eq!(Day8.part_two(&map), Some("34".to_string()));
+    }
+
+    #[test]
+    fn handles_maps_that_are_not_square() {
+        let map = Day8.parse("..a.a\n.....").unwrap();
+        assert_eq!(Day8.part_one(&map), Some("1".to_string()));
+        assert_eq!(Day8.part_two(&map), Some("3".to_string()));
+
+        let map = Day8.parse("a.\n..\na.\n..\n..").unwrap();
+        assert_eq!(Day8.part_one(&map), Some("1".to_string()));
+        assert_eq!(Day8.part_two(&map), Some("3".to_string()));
+
+        let map = Day8.parse("a.\n..\n..\n").unwrap();
+        assert_eq!(Day8.part_one(&map), Some("0".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_ragged_maps() {
+        let err = Day8.parse("a..\n..\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
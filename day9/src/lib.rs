@@ -0,0 +1,260 @@
+use aoc_common::{
+    error::ParseError,
+    solver::{Answer, Solver},
+};
+use log::{debug, trace};
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    const DAY: u32 = 9;
+
+    fn parse(&self, raw_data: &str) -> Result<Self::Input, ParseError> {
+        return generate_file_system_layouts_from_disk_maps(raw_data);
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Option<Answer> {
+        let fragmented_layouts = generate_fragmented_drive_layouts(input);
+        let checksums = calculate_fragmented_layouts_checksums(&fragmented_layouts);
+        return Some(join_checksums(&checksums));
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Option<Answer> {
+        let contigious_file_frag = generate_file_contiguous_fragmented_drive_layouts(input);
+        let contigous_checksums = calculate_fragmented_layouts_checksums(&contigious_file_frag);
+        return Some(join_checksums(&contigous_checksums));
+    }
+}
+
+/// One checksum per disk map line, comma separated.
+fn join_checksums(checksums: &Vec<u64>) -> Answer {
+    return checksums
+        .iter()
+        .map(|checksum| checksum.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+}
+
+fn generate_file_system_layouts_from_disk_maps(
+    disk_maps: &str,
+) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut layouts = Vec::new();
+    let mut file_id = 0;
+
+    for line in disk_maps.lines() {
+        let mut char_vec = Vec::new();
+        for (i, (byte_index, digit)) in line.char_indices().enumerate() {
+            let value = digit.to_digit(10).ok_or_else(|| {
+                ParseError::at(
+                    disk_maps,
+                    &line[byte_index..byte_index + digit.len_utf8()],
+                    "expected a digit",
+                )
+            })?;
+            if i % 2 == 0 {
+                for _j in 0..value {
+                    let char = file_id;
+                    //println!("Current Value: {:?}", char);
+                    char_vec.push(char);
+                }
+                file_id += 1;
+            } else {
+                for _j in 0..value {
+                    char_vec.push(-1);
+                }
+            }
+        }
+        file_id = 0;
+        layouts.push(char_vec);
+    }
+    trace!("Disk layouts:\n{}", render_disk_layouts(&layouts));
+    //println!("Input Data Length: {}", disk_maps.len());
+    return Ok(layouts);
+}
+
+fn generate_fragmented_drive_layouts(drive_layouts: &Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    let mut layouts = Vec::new();
+
+    for drive_layout in drive_layouts {
+        let mut fragmented_layout = drive_layout.clone();
+        for i in (0..fragmented_layout.len()).rev() {
+            // trace!("{}", render_disk_layout(&fragmented_layout));
+            let value = drive_layout[i];
+            let is_num = value > -1;
+            let Some(first_freespace) = fragmented_layout.iter().position(|&c| c == -1) else {
+                break;
+            };
+            if first_freespace >= i {
+                break;
+            }
+            if is_num {
+                fragmented_layout.swap(i, first_freespace);
+            }
+        }
+        layouts.push(fragmented_layout);
+    }
+    trace!("Disk layouts:\n{}", render_disk_layouts(&layouts));
+    return layouts;
+}
+
+fn generate_file_contiguous_fragmented_drive_layouts(
+    drive_layouts: &Vec<Vec<i32>>,
+) -> Vec<Vec<i32>> {
+    let mut layouts = Vec::new();
+
+    for drive_layout in drive_layouts {
+        let mut fragmented_layout = drive_layout.clone();
+        let num_files = get_max_file_id_from_layout(&fragmented_layout);
+        //println!("Number of Files: {num_files}");
+        for i in (0..=num_files).rev() {
+            let file: Vec<usize> = fragmented_layout
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &byte)| if byte == i { Some(index) } else { None })
+                .collect::<Vec<_>>();
+            let file_len = file.len();
+            let freespace = get_first_size_of_file_freespace_chunk(&fragmented_layout, file_len);
+            if freespace.is_none() {
+                continue;
+            }
+            let freespace = freespace.unwrap();
+            // println!("File ID: {:?}", i);
+            // println!("Freespace: {:?}", freespace);
+            //println!("File_data: {:?}\n", file);
+            if freespace[0] > file[0] {
+                continue;
+            }
+            for j in 0..file_len {
+                fragmented_layout.swap(file[j], freespace[j]);
+            }
+        }
+        layouts.push(fragmented_layout);
+    }
+    trace!("Disk layouts:\n{}", render_disk_layouts(&layouts));
+    return layouts;
+}
+
+fn get_first_size_of_file_freespace_chunk(
+    drive_layout: &Vec<i32>,
+    file_size: usize,
+) -> Option<Vec<usize>> {
+    let mut free_bytes = Vec::new();
+    for i in 0..drive_layout.len() {
+        let byte = drive_layout[i];
+        if byte > -1 {
+            free_bytes.clear();
+            continue;
+        }
+
+        if byte == -1 {
+            free_bytes.push(i);
+        }
+
+        if free_bytes.len() == file_size {
+            return Some(free_bytes);
+        }
+    }
+    return None;
+}
+
+fn get_max_file_id_from_layout(drive_layout: &Vec<i32>) -> i32 {
+    let mut max = 0;
+    for byte in drive_layout {
+        if *byte > max {
+            max = *byte;
+        }
+    }
+    return max;
+}
+
+fn render_disk_layouts(disk_layout: &Vec<Vec<i32>>) -> String {
+    return disk_layout
+        .iter()
+        .map(render_disk_layout)
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+fn render_disk_layout(disk_layout: &Vec<i32>) -> String {
+    let mut rendered = String::new();
+    for char in disk_layout {
+        if *char == -1 {
+            rendered.push_str(". ");
+        } else {
+            rendered.push_str(&format!("{char} "));
+        }
+    }
+    return rendered;
+}
+
+fn calculate_fragmented_layouts_checksums(fragmented_layouts: &Vec<Vec<i32>>) -> Vec<u64> {
+    let mut checksums = Vec::new();
+    for layout in fragmented_layouts {
+        let mut checksum = 0;
+        for i in 0..layout.len() {
+            if layout[i] == -1 {
+                continue;
+            }
+            let value = layout[i];
+            checksum += value as u64 * i as u64;
+        }
+        debug!("Checksum: {:?}", checksum);
+        checksums.push(checksum);
+    }
+    return checksums;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CASE: &str = include_str!("../source_data/test_case.txt");
+
+    #[test]
+    fn layout_expands_disk_map() {
+        let layouts = Day9.parse("12345").unwrap();
+        assert_eq!(
+            layouts,
+            vec![vec![0, -1, -1, 1, 1, 1, -1, -1, -1, -1, 2, 2, 2, 2, 2]]
+        );
+    }
+
+    #[test]
+    fn fragmenting_moves_blocks_left() {
+        let layouts = Day9.parse("12345").unwrap();
+        let fragmented = generate_fragmented_drive_layouts(&layouts);
+        assert_eq!(
+            fragmented[0]
+                .iter()
+                .filter(|block| **block != -1)
+                .cloned()
+                .collect::<Vec<i32>>(),
+            vec![0, 2, 2, 1, 1, 1, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn disk_maps_without_free_space_stay_as_they_are() {
+        let layouts = Day9.parse("5").unwrap();
+        assert_eq!(generate_fragmented_drive_layouts(&layouts), layouts);
+        assert_eq!(Day9.part_one(&layouts), Some("0".to_string()));
+
+        let layouts = Day9.parse("102").unwrap();
+        assert_eq!(Day9.part_one(&layouts), Some("3".to_string()));
+        assert_eq!(Day9.part_two(&layouts), Some("3".to_string()));
+    }
+
+    #[test]
+    fn sample_answers() {
+        let layouts = Day9.parse(TEST_CASE).unwrap();
+        assert_eq!(Day9.part_one(&layouts), Some("1928".to_string()));
+        assert_eq!(Day9.part_two(&layouts), Some("2858".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_non_digits() {
+        assert!(Day9.parse("12a45").is_err());
+    }
+}